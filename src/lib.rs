@@ -3,7 +3,7 @@
 mod tibetan_calculator;
 
 use seed::{prelude::*, *};
-use tibetan_calculator::{tibetan_data::*, *};
+use tibetan_calculator::{ipa::*, parser::*, sanskrit::*, tibetan_data::*, validation::*, *};
 
 // `init` describes what should happen when your app started.
 fn init(_: Url, _: &mut impl Orders<Msg>) -> Model {
@@ -13,28 +13,99 @@ fn init(_: Url, _: &mut impl Orders<Msg>) -> Model {
 // `Model` describes our app state.
 struct Model {
 	tibetan_display: String,
+	wylie_display: String,
+	ipa_display: String,
 	phonetic_display: String,
+	tone_display: String,
+	explanation: Vec<String>,
+	notice: Option<String>,
+	phonetic_options: PhoneticOptions,
+	sanskrit: Option<Transliteration>,
+	phrase: Option<Phrase>,
 	prefix: Option<&'static TibetanCharacter>,
 	superscript: Option<&'static TibetanCharacter>,
 	root: Option<&'static TibetanCharacter>,
 	subscript: Option<&'static TibetanCharacter>,
+	wa_zur: bool,
+	vowel: Option<&'static TibetanVowel>,
 	suffix: Option<&'static TibetanCharacter>,
 	second_suffix: Option<&'static TibetanCharacter>,
+	particle: Option<&'static ContractedParticle>,
+	long_vowel: bool,
+	mark: Option<&'static SanskritMark>,
 }
 
 impl Model {
 	fn new() -> Model {
 		Model {
 			tibetan_display: String::from("ཨ"),
+			wylie_display: String::from(""),
+			ipa_display: String::from(""),
 			phonetic_display: String::from(""),
+			tone_display: String::from(""),
+			explanation: vec![],
+			notice: None,
+			phonetic_options: PhoneticOptions::default(),
+			sanskrit: None,
+			phrase: None,
 			prefix: None,
 			superscript: None,
 			root: None,
 			subscript: None,
+			wa_zur: false,
+			vowel: None,
 			suffix: None,
 			second_suffix: None,
+			particle: None,
+			long_vowel: false,
+			mark: None,
 		}
 	}
+
+	fn syllable(&self) -> Option<TibetanSyllable> {
+		Some(TibetanSyllable {
+			root: self.root?,
+			prefix: self.prefix,
+			superscript: self.superscript,
+			subscript: self.subscript,
+			wa_zur: self.wa_zur,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: self.vowel,
+			suffix: self.suffix,
+			second_suffix: self.second_suffix,
+			particle: self.particle,
+			long_vowel: self.long_vowel,
+			mark: self.mark,
+		})
+	}
+
+	// Start over with a new syllable, keeping the chosen options.
+	fn reset(&mut self) {
+		let phonetic_options = self.phonetic_options;
+		let sanskrit = self.sanskrit;
+		*self = Model::new();
+		self.phonetic_options = phonetic_options;
+		self.sanskrit = sanskrit;
+	}
+
+	fn clear(&mut self, slot: Slot) {
+		match slot {
+			Slot::Prefix => self.prefix = None,
+			Slot::Superscript => self.superscript = None,
+			Slot::Subscript => self.subscript = None,
+			Slot::WaZur => self.wa_zur = false,
+			Slot::Suffix => self.suffix = None,
+			Slot::SecondSuffix => self.second_suffix = None,
+		}
+	}
+
+	fn add_notice(&mut self, notice: String) {
+		self.notice = match self.notice.take() {
+			Some(previous) => Some(format!("{} {}", previous, notice)),
+			None => Some(notice),
+		};
+	}
 }
 
 enum Msg {
@@ -42,8 +113,19 @@ enum Msg {
 	SuperscriptChanged(String),
 	RootChanged(String),
 	SubscriptChanged(String),
+	WaZurChanged(String),
+	VowelChanged(String),
 	SuffixChanged(String),
 	SecondSuffixChanged(String),
+	ParticleChanged(String),
+	LongVowelChanged(String),
+	MarkChanged(String),
+	SchemeChanged(String),
+	ToneNotationChanged(String),
+	GlottalStopChanged(String),
+	VowelLengthChanged(String),
+	SanskritChanged(String),
+	TextEntered(String),
 	NoChange,
 }
 
@@ -59,121 +141,382 @@ macro_rules! update_msg {
 
 // `update` describes how to handle each `Msg`.
 fn update(msg: Msg, model: &mut Model, _: &mut impl Orders<Msg>) {
+	model.notice = None;
 	match msg {
 		Msg::PrefixChanged(s) => update_msg!(s, model.prefix),
 		Msg::SuperscriptChanged(s) => update_msg!(s, model.superscript),
 		Msg::RootChanged(s) => {
-			*model = Model::new();
+			model.reset();
 			let c = s.chars().next();
 			match c {
-				Some(c) => model.root = ROOTS.iter().find(|&t| t.tibetan == c),
+				Some(c) => model.root = all_letters().find(|&t| t.tibetan == c),
 				None => model.root = None,
 			}
 		}
 		Msg::SubscriptChanged(s) => update_msg!(s, model.subscript),
+		Msg::WaZurChanged(s) => model.wa_zur = !s.is_empty(),
+		Msg::VowelChanged(s) => {
+			let c = s.chars().next();
+			match c {
+				Some(c) => model.vowel = VOWELS.iter().find(|&v| v.tibetan == c),
+				None => model.vowel = None,
+			}
+		}
 		Msg::SuffixChanged(s) => update_msg!(s, model.suffix),
 		Msg::SecondSuffixChanged(s) => update_msg!(s, model.second_suffix),
+		Msg::ParticleChanged(s) => {
+			model.particle = CONTRACTED_PARTICLES.iter().find(|&p| p.tibetan == s)
+		}
+		Msg::LongVowelChanged(s) => model.long_vowel = !s.is_empty(),
+		Msg::MarkChanged(s) => {
+			model.mark = SANSKRIT_MARKS.iter().find(|&m| s.starts_with(m.tibetan))
+		}
+		Msg::SchemeChanged(s) => {
+			if let Some(&scheme) = PHONETIC_SCHEMES.iter().find(|t| t.to_string() == s) {
+				model.phonetic_options.scheme = scheme;
+			}
+		}
+		Msg::ToneNotationChanged(s) => {
+			if let Some(&notation) = TONE_NOTATIONS.iter().find(|t| t.to_string() == s) {
+				model.phonetic_options.tone = notation;
+			}
+		}
+		Msg::GlottalStopChanged(s) => model.phonetic_options.glottal_stop = !s.is_empty(),
+		Msg::VowelLengthChanged(s) => model.phonetic_options.vowel_length = !s.is_empty(),
+		Msg::SanskritChanged(s) => {
+			model.sanskrit = TRANSLITERATIONS
+				.iter()
+				.find(|t| t.to_string() == s)
+				.copied()
+		}
+		Msg::TextEntered(s) => match parse_text(&s) {
+			Ok((mut phrase, notice)) => {
+				model.reset();
+				model.notice = notice;
+				if phrase.words.len() == 1
+					&& phrase.words[0].syllables.len() == 1
+					&& phrase.words[0].syllables[0].subjoined.is_empty()
+					&& phrase.words[0].syllables[0].preceding_stacks.is_empty()
+				{
+					let syllable = phrase.words[0].syllables.remove(0);
+					model.root = Some(syllable.root);
+					model.prefix = syllable.prefix;
+					model.superscript = syllable.superscript;
+					model.subscript = syllable.subscript;
+					model.wa_zur = syllable.wa_zur;
+					model.vowel = syllable.vowel;
+					model.suffix = syllable.suffix;
+					model.second_suffix = syllable.second_suffix;
+					model.particle = syllable.particle;
+					model.long_vowel = syllable.long_vowel;
+					model.mark = syllable.mark;
+				} else {
+					// Several syllables, or stacks of Sanskrit, are only displayed,
+					// since the menus describe the slots of a single syllable.
+					model.phrase = Some(phrase);
+				}
+			}
+			Err(e) => {
+				// Tibetan text that cannot be read as Tibetan syllables may still be transliterated as Sanskrit.
+				if is_tibetan(&s) {
+					model.reset();
+					model.tibetan_display = s;
+				}
+				model.notice = Some(e.to_string());
+			}
+		},
 		Msg::NoChange => (),
 	}
 	update_displays(model)
 }
 
+// Parse text entered either in Tibetan Unicode or in Extended Wylie, as a phrase of one or more words.
+// Wylie text is always read as a single word, since spaces separate its syllables.
+// When a single Tibetan syllable could be read in more than one way, the reason for the choice is returned too.
+fn parse_text(text: &str) -> Result<(Phrase, Option<String>), ParseError> {
+	if !is_tibetan(text) {
+		let word = parse_wylie_word(text)?;
+		return Ok((Phrase { words: vec![word] }, None));
+	}
+	let phrase = parse_tibetan_phrase(text)?;
+	let mut notice = None;
+	if phrase.words.len() == 1 && phrase.words[0].syllables.len() == 1 {
+		let readings = parse_tibetan_readings(text)?;
+		if readings.len() > 1 {
+			notice = Some(readings[0].reason.to_owned());
+		}
+	}
+	Ok((phrase, notice))
+}
+
+fn is_tibetan(text: &str) -> bool {
+	text.chars().any(|c| ('\u{0F00}'..='\u{0FFF}').contains(&c))
+}
+
 fn update_displays(model: &mut Model) {
-	if model.root.is_none() {
+	let separate_tone = model.phonetic_options.tone == ToneNotation::Separate;
+	if let Some(phrase) = &model.phrase {
+		model.tibetan_display = tibetan_phrase(phrase);
+		model.wylie_display = wylie_phrase(phrase);
+		model.ipa_display = format!("[{}]", ipa_phrase(phrase));
+		model.phonetic_display = phonetic_phrase(phrase, &model.phonetic_options);
+		// The tone of a word is the tone of its first syllable.
+		let tones: Vec<String> = phrase
+			.words
+			.iter()
+			.map(|w| tone(&w.syllables[0]).to_string())
+			.collect();
+		model.tone_display = if separate_tone {
+			tones.join(", ")
+		} else {
+			String::new()
+		};
+		// Each word is explained in turn, with the sandhi between its syllables.
+		model.explanation = phrase
+			.words
+			.iter()
+			.flat_map(|w| explain_word(w, &model.phonetic_options))
+			.collect();
 		return;
 	}
-	let syllable = TibetanSyllable {
-		root: &model.root.unwrap(),
-		prefix: model.prefix,
-		superscript: model.superscript,
-		subscript: model.subscript,
-		suffix: model.suffix,
-		second_suffix: model.second_suffix,
+	let syllable = match model.syllable() {
+		Some(syllable) => syllable,
+		None => return,
 	};
+	// Remove any letter that can no longer be combined with the others after a change,
+	// and tell the user about it.
+	if let Err(errors) = validate(&syllable) {
+		for error in errors {
+			model.clear(error.slot());
+			model.add_notice(format!("Removed the {}: {}", error.slot(), error));
+		}
+	}
+	let syllable = model.syllable().unwrap();
 	model.tibetan_display = tibetan(&syllable);
-	model.phonetic_display = phonetic(&syllable);
+	model.wylie_display = wylie(&syllable);
+	model.ipa_display = format!("[{}]", ipa(&syllable));
+	model.phonetic_display = phonetic(&syllable, &model.phonetic_options);
+	model.tone_display = if separate_tone {
+		tone(&syllable).to_string()
+	} else {
+		String::new()
+	};
+	// Sanskrit may be read as several syllables, so the syllable is explained as a word.
+	let word = Word {
+		syllables: vec![syllable],
+	};
+	model.explanation = explain_word(&word, &model.phonetic_options);
 }
 
 fn view(model: &Model) -> Node<Msg> {
-	let root_chars: Vec<char> = ROOTS.iter().map(|r| r.tibetan).collect();
-	let available_subscripts: Vec<char> = {
-		if let Some(root) = model.root {
-			root.available_subscripts()
-		} else {
-			vec![]
+	let root_chars: Vec<char> = all_letters().map(|r| r.tibetan).collect();
+	let vowel_chars: Vec<char> = VOWELS.iter().map(|v| v.tibetan).collect();
+	let particles: Vec<&str> = CONTRACTED_PARTICLES.iter().map(|p| p.tibetan).collect();
+	let mark_chars: Vec<char> = SANSKRIT_MARKS.iter().map(|m| m.tibetan).collect();
+	let syllable = model.syllable();
+	let available = |slot: Slot| -> Vec<char> {
+		match &syllable {
+			Some(syllable) => available_letters(syllable, slot),
+			None => vec![],
 		}
 	};
+	let available_prefixes = available(Slot::Prefix);
+	let available_superscripts = available(Slot::Superscript);
+	let available_subscripts = available(Slot::Subscript);
+	let available_wa_zur = available(Slot::WaZur);
+	let available_suffixes = available(Slot::Suffix);
+	let available_second_suffixes = available(Slot::SecondSuffix);
 
 	div![
 		C!["container"],
 		div![&model.tibetan_display, C!["display--tibetan"],],
+		div![&model.wylie_display, C!["display--wylie"],],
+		IF!(!model.ipa_display.is_empty() => div![&model.ipa_display, C!["display--ipa"],]),
 		div![&model.phonetic_display, C!["display--transliteration"],],
+		IF!(!model.tone_display.is_empty() => div![&model.tone_display, C!["display--tone"],]),
+		IF!(!model.explanation.is_empty() => details![
+			C!["explanation"],
+			summary!["Why is it pronounced like this?", C!["explanation__summary"]],
+			ol![model.explanation.iter().map(|step| li![step]), C!["explanation__steps"]],
+		]),
+		model.sanskrit.map(|to| div![
+			transliterate(&model.tibetan_display, to),
+			C!["display--sanskrit"]
+		]),
+		input![
+			C!["text-input"],
+			attrs! {At::Placeholder => "Paste Tibetan text or type Wylie"},
+			input_ev(Ev::Change, Msg::TextEntered),
+		],
+		model
+			.notice
+			.as_ref()
+			.map(|notice| div![notice, C!["notice"]]),
 		div![
 			C!["options"],
 			// prefixes menu
 			view_character_menu(
-				model.prefix,
-				model.root.is_none(),
+				model.prefix.map(|c| c.tibetan),
+				available_prefixes.is_empty(),
 				"prefix",
 				"Prefix",
-				&PREFIXES
+				&available_prefixes[..]
 			),
 			// superscripts menu
 			view_character_menu(
-				model.superscript,
-				model.root.is_none(),
+				model.superscript.map(|c| c.tibetan),
+				available_superscripts.is_empty(),
 				"superscript",
 				"Superscript",
-				&SUPERSCRIPTS
+				&available_superscripts[..]
 			),
 			// roots menu
-			view_character_menu(model.root, false, "root", "Root character", &root_chars[..]),
+			view_character_menu(
+				model.root.map(|c| c.tibetan),
+				false,
+				"root",
+				"Root character",
+				&root_chars[..]
+			),
 			// subscripts menu
 			view_character_menu(
-				model.subscript,
-				available_subscripts.len() == 0,
+				model.subscript.map(|c| c.tibetan),
+				available_subscripts.is_empty(),
 				"subscript",
 				"Subscripts",
 				&available_subscripts[..]
 			),
-			// suffixes menu
+			// wa-zur menu, below the subscript
+			view_character_menu(
+				model.wa_zur.then_some('ཝ'),
+				available_wa_zur.is_empty(),
+				"wa_zur",
+				"Wa-zur",
+				&available_wa_zur[..]
+			),
+			// vowels menu
+			view_character_menu(
+				model.vowel.map(|v| v.tibetan),
+				model.root.is_none(),
+				"vowel",
+				"Vowel",
+				&vowel_chars[..]
+			),
+			// a-chung menu, lengthening the vowel of Sanskrit
+			view_character_menu(
+				model.long_vowel.then_some(A_CHUNG_UNICODE_CODE_POINT),
+				model.root.is_none(),
+				"long_vowel",
+				"A-chung",
+				&[A_CHUNG_UNICODE_CODE_POINT]
+			),
+			// Sanskrit marks menu
 			view_character_menu(
-				model.suffix,
+				model.mark.map(|m| m.tibetan),
 				model.root.is_none(),
+				"mark",
+				"Mark",
+				&mark_chars[..]
+			),
+			// suffixes menu
+			view_character_menu(
+				model.suffix.map(|c| c.tibetan),
+				available_suffixes.is_empty(),
 				"suffix",
 				"Suffix 1",
-				&SUFFIXES
+				&available_suffixes[..]
 			),
 			// second suffixes menu
 			view_character_menu(
-				model.second_suffix,
-				model.suffix.is_none(),
+				model.second_suffix.map(|c| c.tibetan),
+				available_second_suffixes.is_empty(),
 				"second_suffix",
 				"Suffix 2",
-				&SECOND_SUFFIXES
+				&available_second_suffixes[..]
+			),
+			// contracted particles menu, only for open syllables
+			view_character_menu(
+				model.particle.map(|p| p.tibetan),
+				model.root.is_none() || model.suffix.is_some(),
+				"particle",
+				"Particle",
+				&particles[..]
+			),
+			// phonetic scheme menu
+			view_character_menu(
+				Some(model.phonetic_options.scheme),
+				false,
+				"scheme",
+				"Scheme",
+				&PHONETIC_SCHEMES[..]
+			),
+			// tone notation menu
+			view_character_menu(
+				Some(model.phonetic_options.tone),
+				false,
+				"tone_notation",
+				"Tone",
+				&TONE_NOTATIONS[..]
+			),
+			// glottal stop menu
+			view_character_menu(
+				model
+					.phonetic_options
+					.glottal_stop
+					.then_some(GLOTTAL_STOP_UNICODE_CODE_POINT),
+				false,
+				"glottal_stop",
+				"Glottal stop",
+				&[GLOTTAL_STOP_UNICODE_CODE_POINT]
+			),
+			// vowel length menu, only marked in the standard scheme
+			view_character_menu(
+				model.phonetic_options.vowel_length.then_some("ā"),
+				model.phonetic_options.scheme != PhoneticScheme::Standard,
+				"vowel_length",
+				"Vowel length",
+				&["ā"]
+			),
+			// Sanskrit transliteration menu
+			view_character_menu(
+				model.sanskrit,
+				false,
+				"sanskrit",
+				"Sanskrit",
+				&TRANSLITERATIONS[..]
 			),
 		],
 	]
 }
 
-fn view_character_menu(
-	value: Option<&TibetanCharacter>,
+fn view_character_menu<T: ToString>(
+	value: Option<T>,
 	disabled: bool,
 	identifier: &str,
 	label: &str,
-	options: &[char],
+	options: &[T],
 ) -> Node<Msg> {
 	let msg = match identifier {
 		"prefix" => Msg::PrefixChanged,
 		"superscript" => Msg::SuperscriptChanged,
 		"root" => Msg::RootChanged,
 		"subscript" => Msg::SubscriptChanged,
+		"wa_zur" => Msg::WaZurChanged,
+		"vowel" => Msg::VowelChanged,
 		"suffix" => Msg::SuffixChanged,
 		"second_suffix" => Msg::SecondSuffixChanged,
+		"particle" => Msg::ParticleChanged,
+		"long_vowel" => Msg::LongVowelChanged,
+		"mark" => Msg::MarkChanged,
+		"scheme" => Msg::SchemeChanged,
+		"tone_notation" => Msg::ToneNotationChanged,
+		"glottal_stop" => Msg::GlottalStopChanged,
+		"vowel_length" => Msg::VowelLengthChanged,
+		"sanskrit" => Msg::SanskritChanged,
 		_ => |_| Msg::NoChange,
 	};
 	let value = match value {
-		Some(c) => String::from(c.tibetan),
+		Some(c) => c.to_string(),
 		None => String::new(),
 	};
 
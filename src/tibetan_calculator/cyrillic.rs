@@ -0,0 +1,160 @@
+use super::tibetan_data::*;
+use super::Pronunciation;
+
+/// The Cyrillic spelling of each onset of the calculator's own scheme, in a high & in a low tone.
+/// Aspirates are written with "х", and the low-tone initials of the third column as voiced.
+/// An onset ending in "y" is written with a soft sign, followed by an iotated vowel.
+const CYRILLIC_ONSETS: [(&str, &str, &str); 38] = [
+	("", "", ""),
+	("k", "к", "к"),
+	("kh", "кх", "г"),
+	("g", "г", "г"),
+	("ky", "кь", "кь"),
+	("khy", "кхь", "гь"),
+	("gy", "гь", "гь"),
+	("ng", "нг", "нг"),
+	("c", "ч", "ч"),
+	("ch", "чх", "дж"),
+	("j", "дж", "дж"),
+	("ny", "нь", "нь"),
+	("t", "т", "т"),
+	("th", "тх", "д"),
+	("d", "д", "д"),
+	("n", "н", "н"),
+	("p", "п", "п"),
+	("ph", "пх", "б"),
+	("b", "б", "б"),
+	("m", "м", "м"),
+	("ts", "цз", "цз"),
+	("tsh", "ц", "дз"),
+	("dz", "дз", "дз"),
+	("tr", "тр", "тр"),
+	("thr", "тр", "др"),
+	("dr", "др", "др"),
+	("hr", "хр", "хр"),
+	("w", "в", "в"),
+	("zh", "ш", "ш"),
+	("sh", "ш", "ш"),
+	("z", "с", "с"),
+	("s", "с", "с"),
+	("y", "", ""),
+	("r", "р", "р"),
+	("l", "л", "л"),
+	("lh", "лх", "лх"),
+	("h", "х", "х"),
+	("ksh", "кш", "кш"),
+];
+
+/// The Cyrillic spelling of each final consonant of the calculator's own scheme,
+/// including the visarga of Sanskrit, "h".
+const CYRILLIC_CODAS: [(&str, &str); 9] = [
+	("k", "к"),
+	("p", "п"),
+	("ng", "нг"),
+	("n", "н"),
+	("m", "м"),
+	("r", "р"),
+	("l", "л"),
+	("o", "о"),
+	("h", "х"),
+];
+
+const CYRILLIC_VOWELS: &str = "аеёиоуыэюяь";
+
+// Writes the pronunciation of a syllable in the Russian practical transcription, e.g. "кха" for ཁ.
+pub(super) fn cyrillic(pronunciation: &Pronunciation) -> String {
+	let low = matches!(
+		pronunciation.tone,
+		Tone::LOW | Tone::LOW_RISING | Tone::LOW_RISING_FALLING
+	);
+	let mut result = match CYRILLIC_ONSETS
+		.iter()
+		.find(|(onset, _, _)| *onset == pronunciation.onset)
+	{
+		Some((_, high_onset, low_onset)) => {
+			String::from(if low { *low_onset } else { *high_onset })
+		}
+		None => pronunciation.onset.clone(),
+	};
+	// The changed vowels are written "е", "ё" & "ю", and the vowels after a "y" are iotated.
+	let iotated = pronunciation.onset.ends_with('y');
+	result.push_str(match (pronunciation.vowel, pronunciation.umlaut) {
+		('a', true) | ('e', _) if pronunciation.onset.is_empty() => "э",
+		('a', true) | ('e', _) => "е",
+		('a', false) if iotated => "я",
+		('a', false) => "а",
+		('i', _) if pronunciation.onset == "y" => "йи",
+		('i', _) => "и",
+		('o', umlaut) if umlaut || iotated => "ё",
+		('o', _) => "о",
+		('u', umlaut) if umlaut || iotated => "ю",
+		_ => "у",
+	});
+	result.push_str(coda(pronunciation.coda));
+	result
+}
+
+// Returns the Cyrillic spelling of a final consonant, or of a nasal added by sandhi.
+pub(super) fn coda(coda: &str) -> &str {
+	match CYRILLIC_CODAS.iter().find(|(latin, _)| *latin == coda) {
+		Some((_, cyrillic)) => cyrillic,
+		None => coda,
+	}
+}
+
+// A final "нг" is written "н" before a consonant within a word, e.g. "цзонкхапа".
+pub(super) fn simplify_final_ng(word: &mut String) {
+	let chars: Vec<char> = word.chars().collect();
+	let mut result = String::new();
+	for (i, c) in chars.iter().enumerate() {
+		let before_consonant = chars
+			.get(i + 1)
+			.is_some_and(|next| next.is_alphabetic() && !CYRILLIC_VOWELS.contains(*next));
+		if *c == 'г' && i > 0 && chars[i - 1] == 'н' && before_consonant {
+			continue;
+		}
+		result.push(*c);
+	}
+	*word = result;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::parser::*;
+	use super::super::*;
+
+	fn cyrillic(text: &str) -> String {
+		let options = PhoneticOptions {
+			scheme: PhoneticScheme::Cyrillic,
+			tone: ToneNotation::Accents,
+			..PhoneticOptions::default()
+		};
+		phonetic_word(&parse_tibetan_word(text).unwrap(), &options)
+	}
+
+	#[test]
+	fn syllables() {
+		assert_eq!("кха", cyrillic("ཁ"));
+		assert_eq!("га", cyrillic("ག"));
+		assert_eq!("тха", cyrillic("ཐ"));
+		assert_eq!("гья", cyrillic("གྱ"));
+		assert_eq!("ю", cyrillic("ཡུ"));
+		assert_eq!("кюн", cyrillic("ཀུན"));
+		assert_eq!("эн", cyrillic("ཨན"));
+	}
+
+	#[test]
+	fn words() {
+		assert_eq!("цзонкхапа", cyrillic("ཙོང་ཁ་པ"));
+		assert_eq!("кюнга", cyrillic("ཀུན་དགའ"));
+		assert_eq!("ньима", cyrillic("ཉི་མ"));
+	}
+
+	#[test]
+	fn sanskrit() {
+		assert_eq!("ах", cyrillic("ཨཱཿ"));
+		assert_eq!("хрих", cyrillic("ཧྲཱིཿ"));
+		assert_eq!("кша", cyrillic("ཀྵ"));
+		assert_eq!("ом", cyrillic("ཨོཾ"));
+	}
+}
@@ -0,0 +1,156 @@
+use super::tibetan_data::*;
+use super::{pronunciations, Pronunciation};
+
+/// The IPA value of each onset of the phonetic representation.
+/// Voicing is not distinctive in Lhasa Tibetan, where it goes with the low tone, so "g" and "k" are both [k].
+const IPA_ONSETS: [(&str, &str); 38] = [
+	("", ""),
+	("k", "k"),
+	("kh", "kʰ"),
+	("g", "k"),
+	("ky", "kʲ"),
+	("khy", "kʰʲ"),
+	("gy", "kʲ"),
+	("ng", "ŋ"),
+	("c", "tɕ"),
+	("ch", "tɕʰ"),
+	("j", "tɕ"),
+	("ny", "ɲ"),
+	("t", "t"),
+	("th", "tʰ"),
+	("d", "t"),
+	("n", "n"),
+	("p", "p"),
+	("ph", "pʰ"),
+	("b", "p"),
+	("m", "m"),
+	("ts", "ts"),
+	("tsh", "tsʰ"),
+	("dz", "ts"),
+	("tr", "ʈʂ"),
+	("thr", "ʈʂʰ"),
+	("dr", "ʈʂ"),
+	("hr", "ʂ"),
+	("w", "w"),
+	("zh", "ɕ"),
+	("sh", "ɕ"),
+	("z", "s"),
+	("s", "s"),
+	("y", "j"),
+	("r", "r"),
+	("l", "l"),
+	("lh", "l\u{0325}"),
+	("h", "h"),
+	("ksh", "kɕ"),
+];
+
+const NASALISATION_UNICODE_CODE_POINT: char = '\u{0303}';
+const LENGTH_MARK: char = 'ː';
+
+/// Returns a narrow IPA transcription of a Tibetan syllable in its citation form, e.g. "ʈʂʰa˥˥" for ཁྲ,
+/// with the tone written in Chao tone letters.
+/// Sanskrit is transcribed in its customary Tibetan reading, with its syllables separated by ".".
+pub fn ipa(syllable: &TibetanSyllable) -> String {
+	let syllables: Vec<String> = pronunciations(syllable)
+		.iter()
+		.map(ipa_pronunciation)
+		.collect();
+	syllables.join(".")
+}
+
+/// Returns a narrow IPA transcription of the pronunciation of a syllable, as analysed by `pronunciations()`.
+pub fn ipa_pronunciation(pronunciation: &Pronunciation) -> String {
+	let mut result = match IPA_ONSETS
+		.iter()
+		.find(|(latin, _)| *latin == pronunciation.onset)
+	{
+		Some((_, ipa)) => String::from(*ipa),
+		None => pronunciation.onset.clone(),
+	};
+	result.push(match (pronunciation.vowel, pronunciation.umlaut) {
+		('a', true) => 'ɛ',
+		('o', true) => 'ø',
+		('u', true) => 'y',
+		(vowel, _) => vowel,
+	});
+	if pronunciation.long {
+		result.push(LENGTH_MARK);
+	}
+
+	// A final na nasalises the vowel, and a final la only lengthens it, instead of being pronounced.
+	if pronunciation.nasal {
+		result.push(NASALISATION_UNICODE_CODE_POINT);
+	}
+	// A final "k" is itself pronounced as the glottal stop.
+	match pronunciation.coda {
+		"n" if pronunciation.nasal => (),
+		"l" => (),
+		"k" if pronunciation.glottal_stop => (),
+		"ng" => result.push('ŋ'),
+		coda => result.push_str(coda),
+	}
+	if pronunciation.glottal_stop {
+		result.push(GLOTTAL_STOP_UNICODE_CODE_POINT);
+	}
+	result.push_str(pronunciation.tone.tone_letters());
+	result
+}
+
+/// Returns a narrow IPA transcription of a Tibetan word, with its syllables in citation form separated by ".".
+pub fn ipa_word(word: &Word) -> String {
+	let syllables: Vec<String> = word.syllables.iter().map(ipa).collect();
+	syllables.join(".")
+}
+
+/// Returns a narrow IPA transcription of a Tibetan phrase, with its words separated by spaces.
+pub fn ipa_phrase(phrase: &Phrase) -> String {
+	let words: Vec<String> = phrase.words.iter().map(ipa_word).collect();
+	words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::parser::*;
+	use super::*;
+
+	#[test]
+	fn onsets() {
+		assert_eq!("ʈʂʰa˥˥", ipa(&parse_tibetan("ཁྲ").unwrap()));
+		assert_eq!("tɕa˥˥", ipa(&parse_tibetan("ཅ").unwrap()));
+		assert_eq!("ɲa˩˧", ipa(&parse_tibetan("ཉ").unwrap()));
+		assert_eq!("kʰʲa˥˥", ipa(&parse_tibetan("ཁྱ").unwrap()));
+		assert_eq!("ʈʂa˩˧", ipa(&parse_tibetan("སྒྲ").unwrap()));
+		assert_eq!("l\u{0325}a˥˥", ipa(&parse_tibetan("ལྷ").unwrap()));
+	}
+
+	#[test]
+	fn rhymes() {
+		assert_eq!("ky\u{303}˥˥", ipa(&parse_tibetan("ཀུན").unwrap()));
+		assert_eq!("jyː˩˧", ipa(&parse_tibetan("ཡུལ").unwrap()));
+		assert_eq!("kʰaŋ˥˥", ipa(&parse_tibetan("ཁང").unwrap()));
+		assert_eq!("sɛʔ˥˧", ipa(&parse_tibetan("སད").unwrap()));
+		assert_eq!("kaː˥˥", ipa(&parse_tibetan("དཀའ").unwrap()));
+		assert_eq!("lɛː˩˧", ipa(&parse_tibetan("ལས").unwrap()));
+	}
+
+	#[test]
+	fn glottal_stops() {
+		assert_eq!("kɛʔ˥˧", ipa(&parse_tibetan("སྐད").unwrap()));
+		assert_eq!("laʔ˩˧˨", ipa(&parse_tibetan("ལག").unwrap()));
+		assert_eq!("tʰupʔ˥˧", ipa(&parse_tibetan("ཐུབ").unwrap()));
+		assert_eq!("saŋʔ˥˧", ipa(&parse_tibetan("སངས").unwrap()));
+	}
+
+	#[test]
+	fn sanskrit() {
+		assert_eq!("kar˥˥.ma˩˧", ipa(&parse_tibetan("ཀརྨ").unwrap()));
+		assert_eq!("pe˥˥.ma˥˥", ipa(&parse_tibetan("པདྨ").unwrap()));
+		assert_eq!("kɕa˥˥", ipa(&parse_tibetan("ཀྵ").unwrap()));
+	}
+
+	#[test]
+	fn word() {
+		let word = parse_tibetan_word("བཀྲ་ཤིས་").unwrap();
+		assert_eq!("ʈʂa˥˥.ɕiː˥˥", ipa_word(&word));
+	}
+}
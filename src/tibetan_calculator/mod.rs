@@ -1,81 +1,822 @@
+pub mod ipa;
+pub mod parser;
+pub mod sandhi;
+pub mod sanskrit;
 pub mod tibetan_data;
+pub mod validation;
 
+mod cyrillic;
+mod pinyin;
+mod thl;
+
+use parser::is_stack;
+use sandhi::apply_sandhi;
+use sanskrit::{transliterate, Transliteration};
 use tibetan_data::*;
 
 /// Returns an entire Tibetan syllable as a String.
 pub fn tibetan(syllable: &TibetanSyllable) -> String {
-	let mut result = String::new();
+	let mut result: String = syllable
+		.preceding_stacks
+		.iter()
+		.map(Stack::tibetan)
+		.collect();
 	if let Some(prefix) = syllable.prefix {
 		result.push(prefix.unicode_code_point);
 	}
-	if let Some(superscript) = syllable.superscript {
-		result.push(superscript.unicode_code_point);
-		result.push(syllable.root.unicode_code_point_as_subscript);
-	} else {
-		result.push(syllable.root.unicode_code_point);
-	}
-	if let Some(subscript) = syllable.subscript {
-		result.push(subscript.unicode_code_point_as_subscript);
-	}
+	result.push_str(&syllable.root_stack().tibetan());
 	if let Some(suffix) = syllable.suffix {
 		result.push(suffix.unicode_code_point);
 	}
 	if let Some(second_suffix) = syllable.second_suffix {
 		result.push(second_suffix.unicode_code_point);
 	}
+	if let Some(particle) = syllable.particle {
+		result.push_str(particle.tibetan);
+	}
 	return result;
 }
 
+/// Returns an entire Tibetan word as a String, each syllable followed by a tsheg.
+pub fn tibetan_word(word: &Word) -> String {
+	let mut result = String::new();
+	for syllable in &word.syllables {
+		result.push_str(&tibetan(syllable));
+		result.push(TSHEG_UNICODE_CODE_POINT);
+	}
+	result
+}
+
+/// Returns an entire Tibetan phrase as a String.
+pub fn tibetan_phrase(phrase: &Phrase) -> String {
+	phrase.words.iter().map(tibetan_word).collect()
+}
+
+/// Returns the Extended Wylie transliteration of a Tibetan word, with its syllables separated by spaces.
+pub fn wylie_word(word: &Word) -> String {
+	let syllables: Vec<String> = word.syllables.iter().map(wylie).collect();
+	syllables.join(" ")
+}
+
+/// Returns the Extended Wylie transliteration of a Tibetan phrase.
+/// Since Wylie separates syllables with spaces, word boundaries are not marked.
+pub fn wylie_phrase(phrase: &Phrase) -> String {
+	let words: Vec<String> = phrase.words.iter().map(wylie_word).collect();
+	words.join(" ")
+}
+
+/// Returns the Extended Wylie (EWTS) transliteration of a Tibetan syllable, e.g. "bsgrubs".
+/// A "." is inserted after the prefix only when the syllable would otherwise be read differently,
+/// as in "g.yag", as opposed to "gyag".
+/// The letters of a stack of Sanskrit are joined with "+", as in "n+ta" or "pad+ma".
+pub fn wylie(syllable: &TibetanSyllable) -> String {
+	let mut result = String::new();
+	for (i, stack) in syllable.preceding_stacks.iter().enumerate() {
+		result.push_str(&wylie_stack(stack, i > 0));
+	}
+	let root_stack = syllable.root_stack();
+	if let Some(prefix) = syllable.prefix {
+		result.push_str(prefix.wylie_consonant());
+		let mut letters = vec![prefix];
+		letters.extend(&root_stack.letters);
+		let joined = format!(
+			"{}{}",
+			prefix.wylie_consonant(),
+			wylie_letters(&root_stack.letters, false)
+		);
+		let prefix_merges_with_root = all_letters().any(|r| {
+			r.wylie_consonant().len() > prefix.wylie_consonant().len()
+				&& joined.starts_with(r.wylie_consonant())
+		});
+		if is_stack(&letters) || prefix_merges_with_root {
+			result.push('.');
+		}
+	}
+	result.push_str(&wylie_stack(
+		&root_stack,
+		!syllable.preceding_stacks.is_empty(),
+	));
+	if let Some(suffix) = syllable.suffix {
+		result.push_str(suffix.wylie_consonant());
+	}
+	if let Some(second_suffix) = syllable.second_suffix {
+		result.push_str(second_suffix.wylie_consonant());
+	}
+	if let Some(particle) = syllable.particle {
+		result.push_str(particle.wylie);
+	}
+	result
+}
+
+// Returns the Extended Wylie of a stack, with its vowel & mark.
+// A stack that follows another one in a syllable of Sanskrit is written after its vowel, as in "pad+ma".
+fn wylie_stack(stack: &Stack, follows_stack: bool) -> String {
+	let mut result = wylie_letters(&stack.letters, follows_stack);
+	// A vowel lengthened by an a-chung is written in capitals.
+	match (stack.vowel, stack.long_vowel) {
+		(Some(vowel), true) => result.push_str(&vowel.wylie.to_uppercase()),
+		(Some(vowel), false) => result.push_str(vowel.wylie),
+		(None, true) => result.push('A'),
+		(None, false) => result.push('a'),
+	}
+	if let Some(mark) = stack.mark {
+		result.push_str(mark.wylie);
+	}
+	result
+}
+
+// Returns the Extended Wylie of the letters of a stack, which are joined with "+"
+// unless they make up a native stack, as in "n+ta" & "gya".
+// After another stack, a native stack is also joined when its first letter would be read as a suffix,
+// as in "dhar+ma", as opposed to "wadzra".
+fn wylie_letters(letters: &[&'static TibetanCharacter], follows_stack: bool) -> String {
+	let consonants: Vec<&str> = letters.iter().map(|c| c.wylie_consonant()).collect();
+	let read_as_suffix = follows_stack && SUFFIXES.contains(&letters[0].tibetan);
+	if letters.len() > 1 && (!is_stack(letters) || read_as_suffix) {
+		consonants.join("+")
+	} else {
+		consonants.concat()
+	}
+}
+
+/// How the tone of a syllable is written in its phonetic representation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ToneNotation {
+	/// An accent over the vowel, e.g. "ká".
+	Accents,
+	/// A superscript number after the syllable, e.g. "ka¹".
+	Numbers,
+	/// IPA Chao tone letters after the syllable, e.g. "ka˥˥".
+	ToneLetters,
+	/// Left out of the phonetic representation, to be shown apart with `tone()`.
+	Separate,
+	/// Left out entirely.
+	Omitted,
+}
+
+pub const TONE_NOTATIONS: [ToneNotation; 5] = [
+	ToneNotation::Accents,
+	ToneNotation::Numbers,
+	ToneNotation::ToneLetters,
+	ToneNotation::Separate,
+	ToneNotation::Omitted,
+];
+
+impl std::fmt::Display for ToneNotation {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			ToneNotation::Accents => write!(f, "Accents"),
+			ToneNotation::Numbers => write!(f, "Numbers"),
+			ToneNotation::ToneLetters => write!(f, "IPA tone letters"),
+			ToneNotation::Separate => write!(f, "Separate"),
+			ToneNotation::Omitted => write!(f, "None"),
+		}
+	}
+}
+
+/// The transcription scheme used by `phonetic()` and the functions built on it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PhoneticScheme {
+	/// The calculator's own scheme, which marks aspiration, and changed vowels with a diaeresis, e.g. "drä̀l".
+	Standard,
+	/// The Simplified Phonetic Transcription of the Tibetan & Himalayan Library, e.g. "drel".
+	ThlSimplified,
+	/// The official Tibetan Pinyin of the PRC, used for place names, e.g. "Xigazê".
+	Pinyin,
+	/// The Russian practical transcription in Cyrillic characters, e.g. "Цзонкхапа".
+	Cyrillic,
+	/// A stricter reading of Sanskrit, following its spelling in IAST rather than its customary Tibetan reading,
+	/// e.g. "oṃ" for ཨོཾ. The syllables that are not Sanskrit are read as in the standard scheme.
+	Sanskritic,
+}
+
+pub const PHONETIC_SCHEMES: [PhoneticScheme; 5] = [
+	PhoneticScheme::Standard,
+	PhoneticScheme::ThlSimplified,
+	PhoneticScheme::Pinyin,
+	PhoneticScheme::Cyrillic,
+	PhoneticScheme::Sanskritic,
+];
+
+impl std::fmt::Display for PhoneticScheme {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			PhoneticScheme::Standard => write!(f, "Standard"),
+			PhoneticScheme::ThlSimplified => write!(f, "THL Simplified"),
+			PhoneticScheme::Pinyin => write!(f, "Tibetan Pinyin"),
+			PhoneticScheme::Cyrillic => write!(f, "Cyrillic"),
+			PhoneticScheme::Sanskritic => write!(f, "Sanskritic"),
+		}
+	}
+}
+
+/// Options controlling how `phonetic()` and the functions built on it render Tibetan.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PhoneticOptions {
+	pub scheme: PhoneticScheme,
+	pub tone: ToneNotation,
+	/// Whether a glottal stop closing a word is written, with "ʔ", e.g. "khäʔ" for ཁད.
+	pub glottal_stop: bool,
+	/// Whether a long vowel is written with a macron, e.g. "lǟ" for ལས. Only the standard scheme marks length.
+	pub vowel_length: bool,
+}
+
+impl Default for PhoneticOptions {
+	fn default() -> PhoneticOptions {
+		PhoneticOptions {
+			scheme: PhoneticScheme::Standard,
+			tone: ToneNotation::Accents,
+			glottal_stop: false,
+			vowel_length: false,
+		}
+	}
+}
+
+/// The pronunciation of a syllable in Lhasa Tibetan, from which every phonetic scheme is written.
+/// The consonants are given in the calculator's own scheme, e.g. "thr" for ཁྲ.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pronunciation {
+	/// The initial consonants, e.g. "thr", or "" for a syllable starting with a vowel.
+	pub onset: String,
+	/// The vowel, one of "a", "i", "u", "e" or "o".
+	pub vowel: char,
+	/// Whether the vowel is changed by the suffix, which is written with a diaeresis.
+	pub umlaut: bool,
+	/// Whether the vowel is lengthened by the suffix.
+	pub long: bool,
+	/// Whether the vowel is nasalised by a final na, which is still given as the coda "n".
+	pub nasal: bool,
+	/// The final consonants, e.g. "ng".
+	pub coda: &'static str,
+	pub tone: Tone,
+	/// Whether the syllable closes with a glottal stop, which is only written when the options ask for it.
+	pub glottal_stop: bool,
+}
+
+impl Pronunciation {
+	/// Writes the pronunciation of a single syllable in the scheme and tone notation of the options,
+	/// as `phonetic()` does for its syllable.
+	/// The Sanskritic scheme, which transliterates the spelling rather than the sounds, is written as the standard one.
+	pub fn format(&self, options: &PhoneticOptions) -> String {
+		let options = read_sanskrit_as_standard(options);
+		let glottal_stop = options.glottal_stop && self.glottal_stop;
+		let mut result = self.render_syllable(
+			&options,
+			options.tone == ToneNotation::Accents,
+			glottal_stop,
+		);
+		finish_word(&mut result, Some(self.tone), &options);
+		result
+	}
+
+	// Writes the syllable within a word, with the accent of its tone if it is the first one,
+	// & with a glottal stop if it is heard there.
+	fn render_syllable(
+		&self,
+		options: &PhoneticOptions,
+		accent: bool,
+		glottal_stop: bool,
+	) -> String {
+		// The glottal stop is written in place of a final "k", which is itself pronounced as a glottal stop.
+		let mut result = if glottal_stop && self.coda == "k" {
+			let pronunciation = Pronunciation {
+				coda: "",
+				..self.clone()
+			};
+			render(&pronunciation, options, accent)
+		} else {
+			render(self, options, accent)
+		};
+		if glottal_stop {
+			result.push(GLOTTAL_STOP_UNICODE_CODE_POINT);
+		}
+		result
+	}
+}
+
+// The Sanskritic scheme, which transliterates the spelling rather than the sounds,
+// reads the syllables that are not transliterated as the standard one.
+fn read_sanskrit_as_standard(options: &PhoneticOptions) -> PhoneticOptions {
+	match options.scheme {
+		PhoneticScheme::Sanskritic => PhoneticOptions {
+			scheme: PhoneticScheme::Standard,
+			..*options
+		},
+		_ => *options,
+	}
+}
+
+// Applies the spelling of the end of a word in the scheme, & writes the notation of the tone of its first syllable,
+// unless the word has no tone, as when it is transliterated.
+fn finish_word(word: &mut String, tone: Option<Tone>, options: &PhoneticOptions) {
+	match options.scheme {
+		PhoneticScheme::ThlSimplified => thl::mark_final_e(word),
+		PhoneticScheme::Cyrillic => cyrillic::simplify_final_ng(word),
+		_ => (),
+	}
+	match (options.tone, tone) {
+		(ToneNotation::Numbers, Some(tone)) => word.push(tone.number()),
+		(ToneNotation::ToneLetters, Some(tone)) => word.push_str(tone.tone_letters()),
+		_ => (),
+	}
+}
+
 /// Returns a phonetic representation of a Tibetan syllable in Latin characters.
-pub fn phonetic(syllable: &TibetanSyllable) -> String {
+pub fn phonetic(syllable: &TibetanSyllable, options: &PhoneticOptions) -> String {
+	// Sanskrit may be read as several syllables, or transliterated, so is read as a word.
+	if syllable.is_sanskrit() || options.scheme == PhoneticScheme::Sanskritic {
+		let word = Word {
+			syllables: vec![syllable.clone()],
+		};
+		return phonetic_word(&word, options);
+	}
+	pronunciation(syllable).format(options)
+}
+
+/// Returns the tone of a Tibetan syllable as one of the four contours of Lhasa Tibetan,
+/// which for Sanskrit is the tone of the first syllable of its customary reading.
+pub fn tone(syllable: &TibetanSyllable) -> Tone {
+	pronunciations(syllable)[0].tone
+}
+
+// Analyses the pronunciation of a syllable as spelled, from its root, prefix, superscript, subscript, vowel & suffixes.
+// The syllables of Sanskrit are read through `pronunciations()` instead.
+fn pronunciation(syllable: &TibetanSyllable) -> Pronunciation {
+	explain(syllable).pronunciation
+}
+
+// The pronunciation of a syllable, together with the steps of its analysis in plain English.
+#[derive(Debug, Clone, PartialEq)]
+struct Explanation {
+	pronunciation: Pronunciation,
+	steps: Vec<String>,
+}
+
+// Analyses the pronunciation of a syllable like `pronunciation()`, explaining each rule applied,
+// e.g. "ga with superscript sa becomes voiced \"ga\"." for སྒྲ.
+// The explanation of a whole reading is given by `explain_word()`.
+fn explain(syllable: &TibetanSyllable) -> Explanation {
 	let mut root_phonetic = String::from(syllable.root.phonetic);
 	let mut diairesis = false;
 	let column = &syllable.root.column;
-	let mut tone = Tone::NONE;
-	let mut suffix_phonetic: &str = "";
+	let mut tone = register(syllable.root);
+	let mut suffix_phonetic: &'static str = "";
+	let root = syllable.root.wylie;
+	let mut steps = vec![format!(
+		"The root {} is read \"{}\", with a {} tone.",
+		root, root_phonetic, tone
+	)];
 
 	// Calculate any change in the root, due to the presence of a prefix.
 	// The root change of the subscript overrides the root change for a third column root with prefix.
-	if syllable.prefix.is_some() || syllable.superscript.is_some() {
+	if let Some(added) = match (syllable.superscript, syllable.prefix) {
+		(Some(superscript), _) => Some(format!("superscript {}", superscript.wylie)),
+		(None, Some(prefix)) => Some(format!("prefix {}", prefix.wylie)),
+		(None, None) => None,
+	} {
 		if column == &Column::THIRD && !syllable.root.phonetic_modified_third_column.is_empty() {
 			root_phonetic = syllable.root.phonetic_modified_third_column.to_owned();
+			steps.push(format!(
+				"{} with {} becomes voiced \"{}\".",
+				root, added, root_phonetic
+			));
 		}
-		if column == &Column::FOURTH {
+		if column == &Column::FOURTH || CHARACTERS_RAISED_BY_PREFIX.contains(&syllable.root.tibetan)
+		{
 			tone = Tone::HIGH;
+			steps.push(format!("{} with {} takes a high tone.", root, added));
 		}
 	}
 
-	// Calculate any change in the root, due to the presence of a subscript.
+	// Calculate any change in the root, due to the presence of a subscript,
+	// or of a stack with an irregular reading, like ལྷ.
 	// This may overwrite changes due to prefix, above.
-	if syllable.subscript.is_some() {
+	let ligature = LIGATURES.iter().find(|l| {
+		l.root == syllable.root.tibetan
+			&& l.superscript == syllable.superscript.map(|s| s.tibetan)
+			&& l.subscript == syllable.subscript.map(|s| s.tibetan)
+	});
+	if let Some(ligature) = ligature {
+		root_phonetic = ligature.phonetic.to_owned();
+		tone = ligature.tone;
+		let stack: String = syllable
+			.stack()
+			.iter()
+			.map(|c| c.wylie_consonant())
+			.collect();
+		steps.push(format!(
+			"The stack {}a is read irregularly as \"{}\", with a {} tone.",
+			stack, root_phonetic, tone
+		));
+	} else if let Some(subscript) = syllable.subscript {
 		let (changed_root, changed_tone) =
-			subscript_phonetic_change(syllable, syllable.subscript.unwrap(), root_phonetic, tone);
+			subscript_phonetic_change(syllable, subscript, root_phonetic.clone(), tone);
+		if changed_root != root_phonetic || changed_tone != tone {
+			steps.push(format!(
+				"{} with subscript {} becomes \"{}\", with a {} tone.",
+				root, subscript.wylie, changed_root, changed_tone
+			));
+		}
 		root_phonetic = changed_root;
 		tone = changed_tone;
 	}
 
-	// Calculate any change in the root, due to the presence of a suffix.
-	if syllable.suffix.is_some() {
-		if SUFFIXES_THAT_CAUSE_VOWEL_CHANGE.contains(&syllable.suffix.unwrap().tibetan) {
+	// A ba after the prefix da is read "w", which merges into the vowel "u", or "y" with the subscript ya,
+	// e.g. དབང "wang", དབུ "u" & དབྱངས "yang".
+	if syllable.prefix.is_some_and(|p| p.tibetan == 'ད')
+		&& syllable.root.tibetan == 'བ'
+		&& syllable.superscript.is_none()
+	{
+		let changed_root = match (
+			syllable.subscript.map(|s| s.tibetan),
+			syllable.vowel.map(|v| v.phonetic),
+		) {
+			(Some('ཡ'), _) => Some("ya"),
+			(None, Some("u")) => Some("a"),
+			(None, _) => Some("wa"),
+			_ => None,
+		};
+		if let Some(changed_root) = changed_root {
+			let stack: String = syllable
+				.stack()
+				.iter()
+				.map(|c| c.wylie_consonant())
+				.collect();
+			root_phonetic = changed_root.to_owned();
+			steps.push(if root_phonetic == "a" {
+				format!(
+					"{}a with prefix da is silent before the vowel \"u\".",
+					stack
+				)
+			} else {
+				format!("{}a with prefix da is read \"{}\".", stack, root_phonetic)
+			});
+		}
+	}
+
+	// Replace the inherent "a" of the root with the vowel sign, if there is one.
+	if let Some(vowel) = syllable.vowel {
+		root_phonetic = replace_phonetic_vowel(root_phonetic, vowel.phonetic);
+		steps.push(format!(
+			"The vowel sign {} replaces the inherent \"a\" with \"{}\".",
+			vowel.wylie, vowel.phonetic
+		));
+	}
+
+	// Calculate any change in the root, due to the presence of a suffix or a contracted particle.
+	// Only "a", "u" & "o" take a diairesis.
+	let vowel_takes_diairesis = match syllable.vowel {
+		Some(vowel) => VOWELS_THAT_TAKE_DIAIRESIS.contains(&vowel.tibetan),
+		None => true,
+	};
+	let vowel_phonetic = root_phonetic.chars().last().unwrap_or('a');
+	if let Some(suffix) = syllable.suffix {
+		if vowel_takes_diairesis && SUFFIXES_THAT_CAUSE_VOWEL_CHANGE.contains(&suffix.tibetan) {
 			diairesis = true;
+			steps.push(format!(
+				"The suffix {} changes the vowel \"{}\" to \"{}{}\".",
+				suffix.wylie, vowel_phonetic, vowel_phonetic, DIAIRESIS_UNICODE_CODE_POINT
+			));
+		}
+		suffix_phonetic = suffix.phonetic_as_suffix;
+		if suffix_phonetic.is_empty() {
+			steps.push(format!("The suffix {} is not pronounced.", suffix.wylie));
+		} else {
+			steps.push(format!(
+				"The suffix {} closes the syllable with \"{}\".",
+				suffix.wylie, suffix_phonetic
+			));
+		}
+	}
+	if let Some(particle) = syllable.particle {
+		if vowel_takes_diairesis && particle.causes_vowel_change {
+			diairesis = true;
+			steps.push(format!(
+				"The contracted particle {} changes the vowel \"{}\" to \"{}{}\".",
+				particle.wylie, vowel_phonetic, vowel_phonetic, DIAIRESIS_UNICODE_CODE_POINT
+			));
+		}
+		suffix_phonetic = particle.phonetic;
+	}
+	// A mark of Sanskrit closes the syllable, e.g. "om" for ཨོཾ.
+	if let Some(mark) = syllable.mark {
+		suffix_phonetic = mark.phonetic;
+		steps.push(format!(
+			"The mark {} closes the syllable with \"{}\".",
+			mark.wylie, suffix_phonetic
+		));
+	}
+
+	// The letters subjoined in a stack of Sanskrit are not pronounced in the customary reading,
+	// which is otherwise given by `CUSTOMARY_READINGS`.
+	if !syllable.subjoined.is_empty() {
+		steps.push(String::from(
+			"The letters subjoined in the stack of Sanskrit are not pronounced.",
+		));
+	}
+	let vowel = root_phonetic.pop().unwrap_or('a');
+
+	let glottal_stop = glottal_stop(syllable);
+	let long = syllable
+		.suffix
+		.is_some_and(|s| SUFFIXES_THAT_LENGTHEN_VOWEL.contains(&s.tibetan))
+		&& !glottal_stop;
+	let nasal = syllable.suffix.is_some_and(|s| s.tibetan == 'ན');
+	if let (true, Some(suffix)) = (long, syllable.suffix) {
+		steps.push(format!("The suffix {} lengthens the vowel.", suffix.wylie));
+	}
+	if nasal {
+		steps.push(String::from("The suffix na nasalises the vowel."));
+	}
+	let contour = contour(tone, syllable);
+	if glottal_stop {
+		steps.push(format!(
+			"The syllable closes with a glottal stop, so the {} tone becomes {}.",
+			tone, contour
+		));
+	} else {
+		steps.push(format!("The {} tone becomes {}.", tone, contour));
+	}
+
+	Explanation {
+		pronunciation: Pronunciation {
+			onset: root_phonetic,
+			vowel,
+			umlaut: diairesis,
+			long,
+			nasal,
+			coda: suffix_phonetic,
+			tone: contour,
+			glottal_stop,
+		},
+		steps,
+	}
+}
+
+// Writes the pronunciation of a syllable in the scheme of the options, with an accent for the tone if asked to.
+fn render(pronunciation: &Pronunciation, options: &PhoneticOptions, accent: bool) -> String {
+	match options.scheme {
+		PhoneticScheme::Standard => {
+			let mut result = pronunciation.onset.clone();
+			result.push(pronunciation.vowel);
+			if pronunciation.umlaut {
+				result.push(DIAIRESIS_UNICODE_CODE_POINT);
+			}
+			if pronunciation.long && options.vowel_length {
+				result.push(MACRON_UNICODE_CODE_POINT);
+			}
+			if accent {
+				result.push(pronunciation.tone.accent());
+			}
+			result.push_str(pronunciation.coda);
+			result
 		}
-		suffix_phonetic = syllable.suffix.unwrap().phonetic_as_suffix;
+		// The other schemes write neither tones with accents, nor vowel length.
+		PhoneticScheme::ThlSimplified => thl::thl(pronunciation),
+		PhoneticScheme::Pinyin => pinyin::pinyin(pronunciation),
+		PhoneticScheme::Cyrillic => cyrillic::cyrillic(pronunciation),
+		PhoneticScheme::Sanskritic => unreachable!("Sanskrit is transliterated rather than read"),
 	}
+}
 
-	// Combine the appropriate unicode code points to form the final string.
-	let mut phonetic = String::from(root_phonetic);
-	if diairesis {
-		phonetic.push(DIAIRESIS_UNICODE_CODE_POINT);
+/// Returns the pronunciation of each syllable of the reading of a Tibetan syllable.
+/// A syllable of Sanskrit may be read as several, following its customary Tibetan reading,
+/// either from `CUSTOMARY_READINGS` or from its stacks, e.g. "kar" & "ma" for ཀརྨ.
+pub fn pronunciations(syllable: &TibetanSyllable) -> Vec<Pronunciation> {
+	let syllables = parser::customary_syllables(syllable);
+	// The tone of the reading is the register of its first syllable, whose final consonants are dropped,
+	// so that it is never falling.
+	let tone = match pronunciation(&syllables[0]).tone {
+		Tone::HIGH_FALLING => Tone::HIGH_LEVEL,
+		Tone::LOW_RISING_FALLING => Tone::LOW_RISING,
+		tone => tone,
+	};
+	match CUSTOMARY_READINGS
+		.iter()
+		.find(|r| r.tibetan == tibetan(syllable))
+	{
+		Some(reading) => reading
+			.syllables
+			.iter()
+			.map(|&(onset, vowel, coda)| Pronunciation {
+				onset: onset.to_owned(),
+				vowel,
+				umlaut: false,
+				long: false,
+				nasal: false,
+				coda,
+				tone,
+				glottal_stop: false,
+			})
+			.collect(),
+		None => syllables.iter().map(pronunciation).collect(),
 	}
-	if tone == Tone::HIGH {
-		phonetic.push(HIGH_TONE_UNICODE_CODE_POINT);
+}
+
+/// Returns a phonetic representation of a Tibetan word in Latin characters, written as a single word,
+/// e.g. "sângyä" for སངས་རྒྱས་.
+/// The syllables after the first are changed by the sandhi of standard Lhasa reading.
+/// Sanskrit is given its customary Tibetan reading, e.g. "benza" for བཛྲ, except in the Sanskritic scheme,
+/// which transliterates the syllables of Sanskrit & reads the others in the standard scheme.
+pub fn phonetic_word(word: &Word, options: &PhoneticOptions) -> String {
+	let transliterated = |syllable: &TibetanSyllable| is_transliterated(syllable, options);
+	let options = &read_sanskrit_as_standard(options);
+	let mut result = String::new();
+	// The coda closing the previous syllable, as analysed rather than as written.
+	let mut coda = "";
+	for (i, syllable) in word.syllables.iter().enumerate() {
+		if transliterated(syllable) {
+			result.push_str(&transliterate(&tibetan(syllable), Transliteration::Iast));
+			coda = "";
+			continue;
+		}
+		let mut pronunciations = pronunciations(syllable);
+		if i > 0 {
+			let onset = std::mem::take(&mut pronunciations[0].onset);
+			let (nasal, changed_onset) = apply_sandhi(&word.syllables[i - 1], syllable, onset);
+			// The spelling of a transliterated syllable is not changed.
+			if !transliterated(&word.syllables[i - 1]) && !nasal.is_empty() {
+				result.push_str(match options.scheme {
+					PhoneticScheme::Cyrillic => cyrillic::coda(nasal),
+					_ => nasal,
+				});
+				coda = nasal;
+			}
+			pronunciations[0].onset = changed_onset;
+		}
+		let last_pronunciation = pronunciations.len() - 1;
+		for (j, pronunciation) in pronunciations.iter().enumerate() {
+			// A glottal stop is only heard at the end of a word, since within a word it merges into the next consonant.
+			let glottal_stop = options.glottal_stop
+				&& pronunciation.glottal_stop
+				&& i == word.syllables.len() - 1
+				&& j == last_pronunciation;
+			// Only the first syllable of a word carries a distinctive tone.
+			let accent = i == 0 && j == 0 && options.tone == ToneNotation::Accents;
+			let mut syllable_phonetic =
+				pronunciation.render_syllable(options, accent, glottal_stop);
+			// The "g" of a final "ng" is not repeated at the start of the next syllable,
+			// e.g. "sangyä" rather than "sanggyä", while other consonants are doubled, as in "künnä".
+			if coda == "ng"
+				&& pronunciation.onset.starts_with('g')
+				&& result.chars().last() == syllable_phonetic.chars().next()
+			{
+				syllable_phonetic.remove(0);
+			}
+			result.push_str(&syllable_phonetic);
+			coda = pronunciation.coda;
+		}
 	}
-	if tone == Tone::LOW {
-		phonetic.push(LOW_TONE_UNICODE_CODE_POINT);
+	// The notations following a syllable are written after the whole word.
+	let tone = word
+		.syllables
+		.first()
+		.filter(|s| !transliterated(s))
+		.map(tone);
+	finish_word(&mut result, tone, options);
+	result
+}
+
+// Returns whether a syllable is transliterated rather than read, as Sanskrit is in the Sanskritic scheme.
+fn is_transliterated(syllable: &TibetanSyllable, options: &PhoneticOptions) -> bool {
+	options.scheme == PhoneticScheme::Sanskritic
+		&& (syllable.is_sanskrit()
+			|| CUSTOMARY_READINGS
+				.iter()
+				.any(|r| r.tibetan == tibetan(syllable)))
+}
+
+/// Explains the reading of a word by `phonetic_word()`, following the same path: the customary reading of Sanskrit,
+/// the analysis of each syllable, & the sandhi between them, ending with the reading itself.
+/// The steps of a syllable are headed by the syllable when the word has several.
+pub fn explain_word(word: &Word, options: &PhoneticOptions) -> Vec<String> {
+	let mut steps = vec![];
+	for (i, syllable) in word.syllables.iter().enumerate() {
+		let mut syllable_steps = vec![];
+		if is_transliterated(syllable, options) {
+			syllable_steps.push(format!(
+				"The Sanskrit is transliterated as \"{}\".",
+				transliterate(&tibetan(syllable), Transliteration::Iast)
+			));
+		} else if CUSTOMARY_READINGS
+			.iter()
+			.any(|r| r.tibetan == tibetan(syllable))
+		{
+			let standard = PhoneticOptions {
+				scheme: PhoneticScheme::Standard,
+				tone: ToneNotation::Omitted,
+				..*options
+			};
+			let reading: String = pronunciations(syllable)
+				.iter()
+				.map(|p| render(p, &standard, false))
+				.collect();
+			syllable_steps.push(format!(
+				"The Sanskrit is given its customary reading \"{}\".",
+				reading
+			));
+		} else {
+			let syllables = parser::customary_syllables(syllable);
+			if syllables.len() > 1 {
+				let parts: Vec<String> = syllables.iter().map(wylie).collect();
+				syllable_steps.push(format!(
+					"Each stack of the Sanskrit is read as a syllable of its own: {}.",
+					parts.join(", ")
+				));
+				for part in &syllables {
+					let part_wylie = wylie(part);
+					syllable_steps.extend(
+						explain(part)
+							.steps
+							.into_iter()
+							.map(|step| format!("{}: {}", part_wylie, step)),
+					);
+				}
+			} else {
+				syllable_steps.extend(explain(syllable).steps);
+			}
+		}
+
+		// The sandhi with the previous syllable, as applied by `phonetic_word()`.
+		if i > 0 && !is_transliterated(syllable, options) {
+			let previous = &word.syllables[i - 1];
+			let onset = pronunciations(syllable)[0].onset.clone();
+			let (nasal, changed_onset) = apply_sandhi(previous, syllable, onset.clone());
+			if !nasal.is_empty() && !is_transliterated(previous, options) {
+				syllable_steps.push(format!(
+					"The prefix {} closes the previous syllable with the nasal \"{}\".",
+					syllable.prefix.map_or("", |p| p.wylie),
+					nasal
+				));
+			}
+			if changed_onset != onset {
+				syllable_steps.push(format!(
+					"After the previous syllable, the onset \"{}\" becomes \"{}\".",
+					onset, changed_onset
+				));
+			}
+		}
+
+		if word.syllables.len() > 1 {
+			let text = tibetan(syllable);
+			steps.extend(
+				syllable_steps
+					.into_iter()
+					.map(|step| format!("{}: {}", text, step)),
+			);
+		} else {
+			steps.extend(syllable_steps);
+		}
 	}
-	phonetic.push_str(suffix_phonetic);
+	steps.push(format!(
+		"{} is read \"{}\".",
+		tibetan_word(word).trim_end_matches(TSHEG_UNICODE_CODE_POINT),
+		phonetic_word(word, options)
+	));
+	steps
+}
+
+/// Returns a phonetic representation of a Tibetan phrase in Latin characters, with its words separated by spaces.
+pub fn phonetic_phrase(phrase: &Phrase, options: &PhoneticOptions) -> String {
+	let words: Vec<String> = phrase
+		.words
+		.iter()
+		.map(|w| phonetic_word(w, options))
+		.collect();
+	words.join(" ")
+}
 
-	phonetic
+/// Returns the register of a root character without a prefix or superscript:
+/// high for the first & second columns, low for the third & fourth.
+pub fn register(root: &TibetanCharacter) -> Tone {
+	match root.column {
+		Column::FIRST | Column::SECOND => Tone::HIGH,
+		Column::THIRD | Column::FOURTH => Tone::LOW,
+		Column::NONE if HIGH_TONE_CHARACTERS.contains(&root.tibetan) => Tone::HIGH,
+		Column::NONE => Tone::LOW,
+	}
+}
+
+/// Returns whether a syllable closes with a glottal stop, which gives it a falling contour & a short vowel:
+/// after the suffixes ག, བ & ད, or after the second suffix ས following ག, ང, བ or མ.
+pub fn glottal_stop(syllable: &TibetanSyllable) -> bool {
+	let suffix = syllable.suffix.map(|s| s.tibetan);
+	let second_suffix = syllable.second_suffix.map(|s| s.tibetan);
+	suffix.is_some_and(|s| SUFFIXES_THAT_CAUSE_FALLING_TONE.contains(&s))
+		|| (second_suffix == Some('ས')
+			&& suffix.is_some_and(|s| SUFFIXES_GLOTTALISED_BY_SECOND_SUFFIX.contains(&s)))
+}
+
+/// Returns the contour of a syllable with the given register.
+/// A syllable closed by a glottal stop has a falling contour.
+pub fn contour(register: Tone, syllable: &TibetanSyllable) -> Tone {
+	let falling = glottal_stop(syllable);
+	match (register, falling) {
+		(Tone::HIGH, false) | (Tone::HIGH_LEVEL, _) => Tone::HIGH_LEVEL,
+		(Tone::HIGH, true) | (Tone::HIGH_FALLING, _) => Tone::HIGH_FALLING,
+		(Tone::LOW, false) | (Tone::LOW_RISING, _) => Tone::LOW_RISING,
+		(Tone::LOW, true) | (Tone::LOW_RISING_FALLING, _) => Tone::LOW_RISING_FALLING,
+	}
 }
 
 fn subscript_phonetic_change(
@@ -85,7 +826,8 @@ fn subscript_phonetic_change(
 	tone: Tone,
 ) -> (String, Tone) {
 	// There are three possible subscripts: ra, ya, & la.
-	// For each of them, go through all possible combinations with a root.
+	// For ra & ya, go through all possible combinations with a root.
+	// The combinations with la are all irregular, and found in `LIGATURES`.
 	return match subscript.tibetan {
 		// ra subscript
 		'ར' => match syllable.root.tibetan {
@@ -102,11 +844,6 @@ fn subscript_phonetic_change(
 			'ཧ' => ("hra".to_owned(), tone),
 			_ => (root_phonetic, tone),
 		},
-		// la subscript
-		'ལ' => match syllable.root.tibetan {
-			'ཟ' => ("da".to_owned(), Tone::LOW),
-			_ => ("la".to_owned(), Tone::HIGH),
-		},
 		// ya subscript
 		'ཡ' => match syllable.root.tibetan {
 			'མ' => ("nya".to_owned(), Tone::LOW),
@@ -127,6 +864,14 @@ fn add_phonetic_ya_subscript<'a>(root_phonetic: String) -> String {
 	r
 }
 
+// Replace the final "a" character of the phonetic representation
+// of the character with the given vowel.
+fn replace_phonetic_vowel(root_phonetic: String, vowel_phonetic: &str) -> String {
+	let mut r = root_phonetic.split_at(root_phonetic.len() - 1).0.to_owned();
+	r.push_str(vowel_phonetic);
+	r
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -138,12 +883,20 @@ mod tests {
 			prefix: None,
 			superscript: None,
 			subscript: ROOTS.iter().find(|&t| t.tibetan == 'ར'),
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: None,
 			suffix: None,
 			second_suffix: None,
+			particle: None,
+			long_vowel: false,
+			mark: None,
 		};
 
 		assert_eq!("ཏྲ", tibetan(&syllable));
-		assert_eq!("trá", phonetic(&syllable));
+		assert_eq!("tra", wylie(&syllable));
+		assert_eq!("trá", phonetic(&syllable, &PhoneticOptions::default()));
 	}
 
 	#[test]
@@ -153,12 +906,19 @@ mod tests {
 			prefix: None,
 			superscript: None,
 			subscript: ROOTS.iter().find(|&t| t.tibetan == 'ཡ'),
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: None,
 			suffix: None,
 			second_suffix: None,
+			particle: None,
+			long_vowel: false,
+			mark: None,
 		};
 
 		assert_eq!("གྱ", tibetan(&syllable));
-		assert_eq!("khya", phonetic(&syllable));
+		assert_eq!("khyà", phonetic(&syllable, &PhoneticOptions::default()));
 	}
 
 	#[test]
@@ -168,11 +928,585 @@ mod tests {
 			prefix: None,
 			superscript: ROOTS.iter().find(|&t| t.tibetan == 'ས'),
 			subscript: ROOTS.iter().find(|&t| t.tibetan == 'ར'),
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: None,
 			suffix: ROOTS.iter().find(|&t| t.tibetan == 'ལ'),
 			second_suffix: None,
+			particle: None,
+			long_vowel: false,
+			mark: None,
 		};
 
 		assert_eq!("སྒྲལ", tibetan(&syllable));
-		assert_eq!("drä̀l", phonetic(&syllable));
+		assert_eq!("drä̀l", phonetic(&syllable, &PhoneticOptions::default()));
+	}
+
+	#[test]
+	fn with_vowel_and_diairesis() {
+		let syllable = TibetanSyllable {
+			root: ROOTS.iter().find(|&t| t.tibetan == 'ཀ').unwrap(),
+			prefix: None,
+			superscript: None,
+			subscript: None,
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: VOWELS.iter().find(|&v| v.tibetan == 'ོ'),
+			suffix: ROOTS.iter().find(|&t| t.tibetan == 'ན'),
+			second_suffix: None,
+			particle: None,
+			long_vowel: false,
+			mark: None,
+		};
+
+		assert_eq!("ཀོན", tibetan(&syllable));
+		assert_eq!("kon", wylie(&syllable));
+		assert_eq!("kö́n", phonetic(&syllable, &PhoneticOptions::default()));
+	}
+
+	#[test]
+	fn with_vowel_without_diairesis() {
+		let syllable = TibetanSyllable {
+			root: ROOTS.iter().find(|&t| t.tibetan == 'ཞ').unwrap(),
+			prefix: None,
+			superscript: None,
+			subscript: None,
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: VOWELS.iter().find(|&v| v.tibetan == 'ི'),
+			suffix: ROOTS.iter().find(|&t| t.tibetan == 'ལ'),
+			second_suffix: None,
+			particle: None,
+			long_vowel: false,
+			mark: None,
+		};
+
+		assert_eq!("ཞིལ", tibetan(&syllable));
+		assert_eq!("zhìl", phonetic(&syllable, &PhoneticOptions::default()));
+	}
+
+	#[test]
+	fn wylie_with_disambiguator() {
+		let syllable = TibetanSyllable {
+			root: ROOTS.iter().find(|&t| t.tibetan == 'ཡ').unwrap(),
+			prefix: ROOTS.iter().find(|&t| t.tibetan == 'ག'),
+			superscript: None,
+			subscript: None,
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: None,
+			suffix: ROOTS.iter().find(|&t| t.tibetan == 'ག'),
+			second_suffix: None,
+			particle: None,
+			long_vowel: false,
+			mark: None,
+		};
+		assert_eq!("g.yag", wylie(&syllable));
+
+		let syllable = TibetanSyllable {
+			root: ROOTS.iter().find(|&t| t.tibetan == 'ག').unwrap(),
+			prefix: ROOTS.iter().find(|&t| t.tibetan == 'བ'),
+			superscript: ROOTS.iter().find(|&t| t.tibetan == 'ས'),
+			subscript: ROOTS.iter().find(|&t| t.tibetan == 'ར'),
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: VOWELS.iter().find(|&v| v.tibetan == 'ུ'),
+			suffix: ROOTS.iter().find(|&t| t.tibetan == 'བ'),
+			second_suffix: ROOTS.iter().find(|&t| t.tibetan == 'ས'),
+			particle: None,
+			long_vowel: false,
+			mark: None,
+		};
+		assert_eq!("bsgrubs", wylie(&syllable));
+
+		// A prefix before ha would otherwise be read as a letter of Sanskrit, as in "bha" for བྷ.
+		for (text, expected) in [("བཧ", "b.ha"), ("དཧ", "d.ha"), ("གཧ", "g.ha")] {
+			let syllable = parser::parse_tibetan(text).unwrap();
+			assert_eq!(expected, wylie(&syllable));
+			assert_eq!(text, tibetan(&parser::parse_wylie(expected).unwrap()));
+		}
+		assert_eq!("\u{0F57}", tibetan(&parser::parse_wylie("bha").unwrap()));
+	}
+
+	#[test]
+	fn word_with_two_syllables() {
+		let word = Word {
+			syllables: vec![
+				TibetanSyllable {
+					root: ROOTS.iter().find(|&t| t.tibetan == 'ས').unwrap(),
+					prefix: None,
+					superscript: None,
+					subscript: None,
+					wa_zur: false,
+					subjoined: vec![],
+					preceding_stacks: vec![],
+					vowel: None,
+					suffix: ROOTS.iter().find(|&t| t.tibetan == 'ང'),
+					second_suffix: ROOTS.iter().find(|&t| t.tibetan == 'ས'),
+					particle: None,
+					long_vowel: false,
+					mark: None,
+				},
+				TibetanSyllable {
+					root: ROOTS.iter().find(|&t| t.tibetan == 'ག').unwrap(),
+					prefix: None,
+					superscript: ROOTS.iter().find(|&t| t.tibetan == 'ར'),
+					subscript: ROOTS.iter().find(|&t| t.tibetan == 'ཡ'),
+					wa_zur: false,
+					subjoined: vec![],
+					preceding_stacks: vec![],
+					vowel: None,
+					suffix: ROOTS.iter().find(|&t| t.tibetan == 'ས'),
+					second_suffix: None,
+					particle: None,
+					long_vowel: false,
+					mark: None,
+				},
+			],
+		};
+
+		assert_eq!("སངས་རྒྱས་", tibetan_word(&word));
+		assert_eq!("sangs rgyas", wylie_word(&word));
+		assert_eq!("sângyä", phonetic_word(&word, &PhoneticOptions::default()));
+	}
+
+	#[test]
+	fn repeated_letters() {
+		let reading = |text: &str, scheme| {
+			let options = PhoneticOptions {
+				scheme,
+				tone: ToneNotation::Omitted,
+				..PhoneticOptions::default()
+			};
+			phonetic_word(&parser::parse_tibetan_word(text).unwrap(), &options)
+		};
+
+		// Only the "g" of a final "ng" merges with the next syllable.
+		assert_eq!(
+			"sangye\u{0301}",
+			reading("སངས་རྒྱས", PhoneticScheme::ThlSimplified)
+		);
+		assert_eq!(
+			"ku\u{0308}nna\u{0308}",
+			reading("ཀུན་ནས", PhoneticScheme::Standard)
+		);
+		for &scheme in PHONETIC_SCHEMES.iter() {
+			assert_eq!(2, reading("ཨ་ཨ", scheme).chars().count());
+		}
+	}
+
+	#[test]
+	fn word_with_sandhi() {
+		let word = parser::parse_tibetan_word("དགའ་བ་").unwrap();
+		assert_eq!("gàwa", phonetic_word(&word, &PhoneticOptions::default()));
+
+		let word = parser::parse_tibetan_word("བཀའ་འགྱུར་").unwrap();
+		assert_eq!("kángyur", phonetic_word(&word, &PhoneticOptions::default()));
+
+		let word = parser::parse_tibetan_word("ཡི་གེ་").unwrap();
+		assert_eq!("yìge", phonetic_word(&word, &PhoneticOptions::default()));
+	}
+
+	#[test]
+	fn with_contracted_particle() {
+		let word = parser::parse_wylie_word("rgyal po'i").unwrap();
+		assert_eq!("རྒྱལ་པོའི་", tibetan_word(&word));
+		assert_eq!("gyä̀lpö", phonetic_word(&word, &PhoneticOptions::default()));
+
+		let syllable = parser::parse_tibetan("དགའི").unwrap();
+		assert_eq!("gä̀", phonetic(&syllable, &PhoneticOptions::default()));
+	}
+
+	#[test]
+	fn contour_tones() {
+		let tone = |text: &str| {
+			let syllable = parser::parse_tibetan(text).unwrap();
+			contour(register(syllable.root), &syllable)
+		};
+
+		assert_eq!(Tone::HIGH_LEVEL, tone("ཀ"));
+		assert_eq!(Tone::HIGH_FALLING, tone("ཀག"));
+		assert_eq!(Tone::LOW_RISING, tone("གང"));
+		assert_eq!(Tone::LOW_RISING_FALLING, tone("ནངས"));
+		assert_eq!(
+			"khǎk",
+			phonetic(
+				&parser::parse_tibetan("གག").unwrap(),
+				&PhoneticOptions::default()
+			)
+		);
+	}
+
+	#[test]
+	fn tone_notations() {
+		let options = |tone| PhoneticOptions {
+			scheme: PhoneticScheme::Standard,
+			tone,
+			..PhoneticOptions::default()
+		};
+		let syllable = parser::parse_tibetan("ཀ").unwrap();
+		assert_eq!("ka¹", phonetic(&syllable, &options(ToneNotation::Numbers)));
+		assert_eq!(
+			"ka˥˥",
+			phonetic(&syllable, &options(ToneNotation::ToneLetters))
+		);
+		assert_eq!("ka", phonetic(&syllable, &options(ToneNotation::Separate)));
+		assert_eq!("ka", phonetic(&syllable, &options(ToneNotation::Omitted)));
+		assert_eq!(Tone::HIGH_LEVEL, tone(&syllable));
+
+		let word = parser::parse_tibetan_word("སངས་རྒྱས་").unwrap();
+		assert_eq!(
+			"sangyä²",
+			phonetic_word(&word, &options(ToneNotation::Numbers))
+		);
+		assert_eq!(
+			"sangyä˥˧",
+			phonetic_word(&word, &options(ToneNotation::ToneLetters))
+		);
+		assert_eq!(
+			"sangyä",
+			phonetic_word(&word, &options(ToneNotation::Omitted))
+		);
+	}
+
+	#[test]
+	fn glottal_stops() {
+		let options = PhoneticOptions {
+			tone: ToneNotation::Omitted,
+			glottal_stop: true,
+			..PhoneticOptions::default()
+		};
+		let reading =
+			|text: &str| phonetic_word(&parser::parse_tibetan_word(text).unwrap(), &options);
+
+		assert_eq!("kha\u{0294}", reading("ཁག"));
+		assert_eq!("kha\u{0308}\u{0294}", reading("ཁད"));
+		assert_eq!("khap\u{0294}", reading("ཁབ"));
+		assert_eq!("sang\u{0294}", reading("སངས"));
+		assert_eq!("khang", reading("ཁང"));
+		assert_eq!("kha\u{0308}", reading("ཁས"));
+		// Within a word, the glottal stop merges into the next consonant.
+		assert_eq!("sangya\u{0308}", reading("སངས་རྒྱས"));
+		assert_eq!("thupta\u{0308}n", reading("ཐུབ་བསྟན"));
+
+		assert!(glottal_stop(&parser::parse_tibetan("བསྒྲུབས").unwrap()));
+		assert!(!glottal_stop(&parser::parse_tibetan("ཁས").unwrap()));
+		let options = PhoneticOptions {
+			glottal_stop: false,
+			..options
+		};
+		assert_eq!(
+			"khak",
+			phonetic(&parser::parse_tibetan("ཁག").unwrap(), &options)
+		);
+	}
+
+	#[test]
+	fn vowel_length() {
+		let options = PhoneticOptions {
+			tone: ToneNotation::Omitted,
+			vowel_length: true,
+			..PhoneticOptions::default()
+		};
+		let reading =
+			|text: &str| phonetic_word(&parser::parse_tibetan_word(text).unwrap(), &options);
+
+		assert_eq!("ka\u{0304}", reading("དཀའ"));
+		assert_eq!("la\u{0308}\u{0304}", reading("ལས"));
+		assert_eq!("gya\u{0308}\u{0304}l", reading("རྒྱལ"));
+		assert_eq!("ka\u{0308}", reading("ཀད"));
+		assert_eq!("ku\u{0308}n", reading("ཀུན"));
+		// The macron is written before the accent of the tone.
+		let options = PhoneticOptions {
+			tone: ToneNotation::Accents,
+			..options
+		};
+		assert_eq!(
+			"la\u{0308}\u{0304}\u{0300}",
+			phonetic(&parser::parse_tibetan("ལས").unwrap(), &options)
+		);
+	}
+
+	#[test]
+	fn pronunciation_of_syllable() {
+		let analysis = |text: &str| pronunciation(&parser::parse_tibetan(text).unwrap());
+
+		assert_eq!(
+			Pronunciation {
+				onset: String::from("k"),
+				vowel: 'u',
+				umlaut: true,
+				long: false,
+				nasal: true,
+				coda: "n",
+				tone: Tone::HIGH_LEVEL,
+				glottal_stop: false,
+			},
+			analysis("ཀུན")
+		);
+		assert_eq!(
+			Pronunciation {
+				onset: String::from("gy"),
+				vowel: 'a',
+				umlaut: true,
+				long: true,
+				nasal: false,
+				coda: "l",
+				tone: Tone::LOW_RISING,
+				glottal_stop: false,
+			},
+			analysis("རྒྱལ")
+		);
+		let sag = analysis("སག");
+		assert!(sag.glottal_stop && !sag.long && !sag.nasal);
+		assert_eq!("k", sag.coda);
+	}
+
+	#[test]
+	fn explanation() {
+		let explanation = explain(&parser::parse_tibetan("སྒྲལ").unwrap());
+		assert_eq!(
+			pronunciation(&parser::parse_tibetan("སྒྲལ").unwrap()),
+			explanation.pronunciation
+		);
+		assert_eq!(
+			vec![
+				"The root ga is read \"kha\", with a low tone.",
+				"ga with superscript sa becomes voiced \"ga\".",
+				"ga with subscript ra becomes \"dra\", with a low tone.",
+				"The suffix la changes the vowel \"a\" to \"a\u{0308}\".",
+				"The suffix la closes the syllable with \"l\".",
+				"The suffix la lengthens the vowel.",
+				"The low tone becomes low rising.",
+			],
+			explanation.steps
+		);
+		let steps = explain(&parser::parse_tibetan("དམག").unwrap()).steps;
+		assert_eq!("ma with prefix da takes a high tone.", steps[1]);
+		assert_eq!(
+			"The syllable closes with a glottal stop, so the high tone becomes high falling.",
+			steps[3]
+		);
+		let steps = explain(&parser::parse_tibetan("ཟླ").unwrap()).steps;
+		assert!(steps.contains(&String::from(
+			"The stack zla is read irregularly as \"da\", with a low tone."
+		)));
+	}
+
+	#[test]
+	fn customary_tone() {
+		let options = PhoneticOptions {
+			tone: ToneNotation::Numbers,
+			..PhoneticOptions::default()
+		};
+		let syllable = parser::parse_tibetan("པདྨ").unwrap();
+		let pronunciations = pronunciations(&syllable);
+		assert_eq!(Tone::HIGH_LEVEL, pronunciations[0].tone);
+		assert!(pronunciations.iter().all(|p| !p.glottal_stop));
+		assert_eq!("pema¹", phonetic(&syllable, &options));
+		assert_eq!(
+			"pe\u{0301}ma",
+			phonetic(&syllable, &PhoneticOptions::default())
+		);
+	}
+
+	#[test]
+	fn sanskrit_reading_agrees() {
+		let options = PhoneticOptions {
+			tone: ToneNotation::Numbers,
+			..PhoneticOptions::default()
+		};
+		for text in &["ཀརྨ", "ཏནྟྲ", "བཛྲ", "པདྨ"] {
+			let syllable = parser::parse_tibetan(text).unwrap();
+			let pronunciations = pronunciations(&syllable);
+			assert!(pronunciations.len() > 1);
+			assert_eq!(pronunciations[0].tone, tone(&syllable));
+			assert!(phonetic(&syllable, &options).ends_with(tone(&syllable).number()));
+			let transcriptions: Vec<String> =
+				pronunciations.iter().map(ipa::ipa_pronunciation).collect();
+			assert_eq!(transcriptions.join("."), ipa::ipa(&syllable));
+		}
+		let syllable = parser::parse_tibetan("ཀརྨ").unwrap();
+		assert!(ipa::ipa(&syllable).starts_with("kar˥˥."));
+		let word = Word {
+			syllables: vec![syllable],
+		};
+		let steps = explain_word(&word, &PhoneticOptions::default());
+		assert!(steps.last().unwrap().ends_with("\"ka\u{0301}rma\"."));
+	}
+
+	#[test]
+	fn word_explanation() {
+		let options = PhoneticOptions::default();
+		for text in &["བཛྲ", "ཏནྟྲ", "སངས་རྒྱས", "བཀའ་འགྱུར", "དགའ་བ", "ཨོཾ་མ་ཎི་པདྨེ་ཧཱུྃ"]
+		{
+			let word = parser::parse_tibetan_word(text).unwrap();
+			let steps = explain_word(&word, &options);
+			let reading = format!("\"{}\".", phonetic_word(&word, &options));
+			assert!(steps.last().unwrap().ends_with(&reading));
+		}
+
+		let word = parser::parse_tibetan_word("བཛྲ").unwrap();
+		assert_eq!(
+			vec![
+				"The Sanskrit is given its customary reading \"benza\".",
+				"\u{0F56}\u{0F5B}\u{0FB2} is read \"be\u{0300}nza\".",
+			],
+			explain_word(&word, &options)
+		);
+
+		let word = parser::parse_tibetan_word("བཀའ་འགྱུར").unwrap();
+		let steps = explain_word(&word, &options);
+		assert!(steps.contains(&String::from(
+			"འགྱུར: The prefix 'a closes the previous syllable with the nasal \"ng\"."
+		)));
+
+		let word = parser::parse_tibetan_word("ཏནྟྲ").unwrap();
+		let steps = explain_word(&word, &options);
+		assert_eq!(
+			"Each stack of the Sanskrit is read as a syllable of its own: tan, tra.",
+			steps[0]
+		);
+	}
+
+	#[test]
+	fn formatted_pronunciation() {
+		let sems = pronunciation(&parser::parse_tibetan("སེམས").unwrap());
+		let options = PhoneticOptions::default();
+		assert_eq!(
+			phonetic(&parser::parse_tibetan("སེམས").unwrap(), &options),
+			sems.format(&options)
+		);
+		let options = PhoneticOptions {
+			scheme: PhoneticScheme::ThlSimplified,
+			tone: ToneNotation::Numbers,
+			..options
+		};
+		assert_eq!(
+			phonetic(&parser::parse_tibetan("སེམས").unwrap(), &options),
+			sems.format(&options)
+		);
+		let sag = pronunciation(&parser::parse_tibetan("སག").unwrap());
+		let options = PhoneticOptions {
+			tone: ToneNotation::Omitted,
+			glottal_stop: true,
+			..PhoneticOptions::default()
+		};
+		assert_eq!("sa\u{0294}", sag.format(&options));
+		assert_eq!(
+			"ky\u{0303}˥˥",
+			ipa::ipa_pronunciation(&pronunciation(&parser::parse_tibetan("ཀུན").unwrap()))
+		);
+	}
+
+	#[test]
+	fn with_ligature() {
+		let reading = |text: &str| {
+			phonetic_word(
+				&parser::parse_tibetan_word(text).unwrap(),
+				&PhoneticOptions::default(),
+			)
+		};
+		assert_eq!("lha\u{0301}", reading("ལྷ"));
+		assert_eq!("lha\u{0301}sa", reading("ལྷ་ས"));
+		assert_eq!("da\u{0300}", reading("ཟླ"));
+		assert_eq!("lu\u{0301}ng", reading("རླུང"));
+		assert_eq!("la\u{0301}ma", reading("བླ་མ"));
+		assert_eq!("ལྷ", tibetan(&parser::parse_wylie("lha").unwrap()));
+	}
+
+	#[test]
+	fn with_prefix_da_before_ba() {
+		let options = PhoneticOptions {
+			tone: ToneNotation::Omitted,
+			..PhoneticOptions::default()
+		};
+		let reading = |text: &str| phonetic(&parser::parse_tibetan(text).unwrap(), &options);
+		assert_eq!("wang", reading("དབང"));
+		assert_eq!("u\u{0308}", reading("དབུས"));
+		assert_eq!("yang", reading("དབྱངས"));
+		// Only the prefix da changes ba, & only when it has no subscript ra.
+		assert_eq!("thrang", reading("དབྲང"));
+		assert_eq!("bang", reading("འབང"));
+
+		let ipa = |text: &str| ipa::ipa(&parser::parse_tibetan(text).unwrap());
+		assert_eq!("waŋ˩˧", ipa("དབང"));
+		assert_eq!("yː˩˧", ipa("དབུས"));
+		assert_eq!("jaŋʔ˩˧˨", ipa("དབྱངས"));
+	}
+
+	#[test]
+	fn with_wa_zur() {
+		let syllable = parser::parse_wylie("grwa").unwrap();
+		assert_eq!("གྲྭ", tibetan(&syllable));
+		assert_eq!("grwa", wylie(&syllable));
+		assert_eq!(
+			"thra\u{0300}",
+			phonetic(&syllable, &PhoneticOptions::default())
+		);
+	}
+
+	#[test]
+	fn sanskrit() {
+		let options = |scheme| PhoneticOptions {
+			scheme,
+			tone: ToneNotation::Omitted,
+			..PhoneticOptions::default()
+		};
+		let reading = |text: &str, scheme| {
+			phonetic_word(&parser::parse_tibetan_word(text).unwrap(), &options(scheme))
+		};
+
+		assert_eq!("benza", reading("བཛྲ", PhoneticScheme::Standard));
+		assert_eq!("benzaguru", reading("བཛྲ་གུ་རུ", PhoneticScheme::Standard));
+		assert_eq!("om", reading("ཨོཾ", PhoneticScheme::Standard));
+		assert_eq!("hung", reading("ཧཱུྃ", PhoneticScheme::Standard));
+		assert_eq!("ah", reading("ཨཱཿ", PhoneticScheme::Standard));
+		assert_eq!("ksha", reading("ཀྵ", PhoneticScheme::Standard));
+		assert_eq!("ta\u{0308}ntra", reading("ཏནྟྲ", PhoneticScheme::Standard));
+		assert_eq!("tentra", reading("ཏནྟྲ", PhoneticScheme::ThlSimplified));
+		assert_eq!("mentra", reading("མནྟྲ", PhoneticScheme::ThlSimplified));
+		assert_eq!("karma", reading("ཀརྨ", PhoneticScheme::Standard));
+		assert_eq!(
+			"ommanipemehung",
+			reading("ཨོཾ་མ་ཎི་པདྨེ་ཧཱུྃ", PhoneticScheme::Standard)
+		);
+
+		assert_eq!("bajraguru", reading("བཛྲ་གུ་རུ", PhoneticScheme::Sanskritic));
+		assert_eq!("hūm\u{0310}", reading("ཧཱུྃ", PhoneticScheme::Sanskritic));
+		assert_eq!("āḥ", reading("ཨཱཿ", PhoneticScheme::Sanskritic));
+		for word in &["གཞིས་ཀ་རྩེ", "ཟླ་བ", "འབྲས་སྤུངས"]
+		{
+			assert_eq!(
+				reading(word, PhoneticScheme::Standard),
+				reading(word, PhoneticScheme::Sanskritic)
+			);
+		}
+		assert_eq!(
+			"o\u{1E43}ma\u{1E47}i",
+			reading("ཨོཾ་མ་ཎི", PhoneticScheme::Sanskritic)
+		);
+	}
+
+	#[test]
+	fn sanskrit_spelling() {
+		let syllable = parser::parse_tibetan("ཧཱུྃ").unwrap();
+		assert!(syllable.long_vowel);
+		assert_eq!("~M", syllable.mark.unwrap().wylie);
+		assert_eq!("ཧཱུྃ", tibetan(&syllable));
+		assert_eq!("hU~M", wylie(&syllable));
+
+		let syllable = parser::parse_wylie("oM").unwrap();
+		assert_eq!("ཨོཾ", tibetan(&syllable));
+		assert_eq!(
+			"\u{0F57}\u{0F71}\u{0F72}",
+			tibetan(&parser::parse_wylie("bhI").unwrap())
+		);
+		assert_eq!("Ta", wylie(&parser::parse_tibetan("ཊ").unwrap()));
 	}
 }
@@ -0,0 +1,1089 @@
+use std::fmt;
+
+use super::tibetan_data::*;
+use super::validation::validate;
+
+/// The reasons a string may fail to be parsed as a Tibetan syllable.
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+	Empty,
+	UnknownCharacter(char),
+	MisplacedVowel(char),
+	InvalidStack(String),
+	InvalidSyllable(String),
+	MissingVowel(String),
+	UnsupportedContraction(String),
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ParseError::Empty => write!(f, "No Tibetan syllable was given."),
+			ParseError::UnknownCharacter(c) => write!(f, "Unknown character '{}'.", c),
+			ParseError::MisplacedVowel(c) => write!(f, "Misplaced vowel sign '{}'.", c),
+			ParseError::InvalidStack(s) => write!(f, "'{}' is not a valid stack.", s),
+			ParseError::InvalidSyllable(s) => write!(f, "'{}' is not a valid syllable.", s),
+			ParseError::MissingVowel(s) => write!(f, "'{}' has no vowel.", s),
+			ParseError::UnsupportedContraction(s) => {
+				write!(f, "The contracted particle in '{}' is not supported.", s)
+			}
+		}
+	}
+}
+
+/// A possible reading of a parsed syllable, along with the rule that led to it.
+#[derive(Debug)]
+pub struct Reading {
+	pub syllable: TibetanSyllable,
+	pub reason: &'static str,
+}
+
+/// Parses a single syllable written in Tibetan Unicode, such as "བསྒྲུབས",
+/// into its prefix, superscript, root, subscript, vowel & suffixes.
+/// A trailing tsheg or shad is ignored.
+/// If the root of the syllable is ambiguous, the traditional reading is returned.
+pub fn parse_tibetan(text: &str) -> Result<TibetanSyllable, ParseError> {
+	let mut readings = parse_tibetan_readings(text)?;
+	Ok(readings.remove(0).syllable)
+}
+
+/// Parses a single syllable written in Tibetan Unicode, returning every valid reading,
+/// ranked from the traditional one to the least likely one.
+/// The returned list is never empty.
+pub fn parse_tibetan_readings(text: &str) -> Result<Vec<Reading>, ParseError> {
+	let text = text
+		.trim()
+		.trim_end_matches([TSHEG_UNICODE_CODE_POINT, SHAD_UNICODE_CODE_POINT]);
+	if text.is_empty() {
+		return Err(ParseError::Empty);
+	}
+	let mut stacks = split_stacks(text)?;
+
+	// A contracted particle is read as the suffix འ of an open syllable, which it then replaces.
+	if let Some(particle) = take_contracted_particle(&mut stacks) {
+		let readings: Vec<Reading> = readings_of_stacks(text, &stacks)?
+			.into_iter()
+			.filter(|r| {
+				r.syllable.suffix.map(|s| s.tibetan) == Some('འ')
+					&& r.syllable.second_suffix.is_none()
+			})
+			.map(|mut r| {
+				r.syllable.suffix = None;
+				r.syllable.particle = Some(particle);
+				r
+			})
+			.collect();
+		if readings.is_empty() {
+			return Err(ParseError::InvalidSyllable(text.to_owned()));
+		}
+		return Ok(readings);
+	}
+	readings_of_stacks(text, &stacks)
+}
+
+// Returns every valid reading of the stacks of a syllable, ranked from the traditional one.
+// A syllable that cannot be read by the rules of Tibetan orthography may be read as Sanskrit,
+// with each stack read with its own vowel, as in པདྨ.
+fn readings_of_stacks(text: &str, stacks: &[Stack]) -> Result<Vec<Reading>, ParseError> {
+	let readings = native_readings(text, stacks);
+	match (readings, sanskrit_syllable(stacks)) {
+		(Err(_), Some(syllable)) => Ok(vec![Reading {
+			syllable,
+			reason:
+				"Each stack of Sanskrit is read with its own vowel, the last one as the root stack.",
+		}]),
+		(readings, _) => readings,
+	}
+}
+
+// Returns every valid reading of the stacks of a syllable by the rules of Tibetan orthography.
+fn native_readings(text: &str, stacks: &[Stack]) -> Result<Vec<Reading>, ParseError> {
+	// A stacked letter or a letter with a vowel sign is always the root.
+	let marked: Vec<usize> = (0..stacks.len())
+		.filter(|&i| stacks[i].is_marked())
+		.collect();
+	match marked[..] {
+		[] => (),
+		[i] => {
+			let reason = if stacks[i].letters.len() > 1 {
+				"The stacked letter is the root."
+			} else {
+				"The letter carrying the vowel sign is the root."
+			};
+			let syllable = syllable_with_root_at(stacks, i)?;
+			return Ok(vec![Reading { syllable, reason }]);
+		}
+		_ => return Err(ParseError::InvalidSyllable(text.to_owned())),
+	}
+
+	// Otherwise, every reading allowed by the prefix & suffix tables is considered,
+	// keeping only the correctly spelled ones when there are any, so that a misspelled syllable
+	// is still read, and the traditional conventions decide between them.
+	let candidates: Vec<usize> = (0..stacks.len())
+		.filter(|&i| syllable_with_root_at(stacks, i).is_ok())
+		.collect();
+	if candidates.is_empty() {
+		return Err(ParseError::InvalidSyllable(text.to_owned()));
+	}
+	let spelled: Vec<usize> = candidates
+		.iter()
+		.copied()
+		.filter(|&i| syllable_with_root_at(stacks, i).is_ok_and(|s| validate(&s).is_ok()))
+		.collect();
+	let mut root_indices = if spelled.is_empty() {
+		candidates
+	} else {
+		spelled
+	};
+	let preferred = if stacks.len() <= 2 { 0 } else { 1 };
+	root_indices.sort_by_key(|&i| i != preferred);
+
+	let ambiguous = root_indices.len() > 1;
+	let readings = root_indices
+		.into_iter()
+		.map(|i| {
+			let syllable = syllable_with_root_at(stacks, i).unwrap();
+			let reason = unstacked_reading_reason(&syllable, stacks.len(), i, ambiguous);
+			Reading { syllable, reason }
+		})
+		.collect();
+	Ok(readings)
+}
+
+// Remove a contracted particle from the end of the stacks, leaving its a-chung as a suffix.
+fn take_contracted_particle(stacks: &mut Vec<Stack>) -> Option<&'static ContractedParticle> {
+	let find = |tibetan: String| CONTRACTED_PARTICLES.iter().find(|p| p.tibetan == tibetan);
+	let length = stacks.len();
+	if length < 2 {
+		return None;
+	}
+
+	// A particle made of the a-chung with a vowel sign, like འི.
+	let last = &stacks[length - 1];
+	if let ([a_chung], Some(vowel)) = (&last.letters[..], last.vowel) {
+		let particle = find(format!("{}{}", a_chung.tibetan, vowel.tibetan));
+		if particle.is_some() {
+			stacks[length - 1].vowel = None;
+		}
+		return particle;
+	}
+
+	// A particle made of the a-chung & a final consonant, like འམ.
+	let before_last = &stacks[length - 2];
+	if length >= 3 && !last.is_marked() && !before_last.is_marked() {
+		let particle = find(format!(
+			"{}{}",
+			before_last.letters[0].tibetan, last.letters[0].tibetan
+		));
+		if particle.is_some() {
+			stacks.pop();
+		}
+		return particle;
+	}
+	None
+}
+
+// The convention by which the letter at the given index of an unstacked syllable is read as its root.
+fn unstacked_reading_reason(
+	syllable: &TibetanSyllable,
+	length: usize,
+	root_index: usize,
+	ambiguous: bool,
+) -> &'static str {
+	let prefix_ga_root_ya =
+		syllable.prefix.map(|p| p.tibetan) == Some('ག') && syllable.root.tibetan == 'ཡ';
+	match (length, root_index) {
+		(1, _) => "A single letter is the root.",
+		(_, 1) if prefix_ga_root_ya => {
+			"Ga before an unstacked ya is a prefix (g.ya), as opposed to ga with a subjoined ya (gya)."
+		}
+		(2, 0) if ambiguous => {
+			"In a two-letter syllable the first letter is the root, \
+			since a prefixed open syllable is written with a final 'a (e.g. དགའ)."
+		}
+		(2, 0) => "In a two-letter syllable the first letter is the root.",
+		(2, _) if ambiguous => "Less likely: the first letter as a prefix, before an open syllable.",
+		(2, _) => "The second letter cannot be a suffix, so the first letter is a prefix.",
+		(3, 1) if ambiguous => {
+			"In a three-letter syllable whose first letter may be a prefix, the middle letter is the root, \
+			followed by a single suffix (e.g. དགས is dgas rather than dags)."
+		}
+		(3, 1) => "In a three-letter syllable the middle letter is the root, after a prefix.",
+		(3, _) if ambiguous => "Less likely: the first letter as the root, followed by two suffixes.",
+		(3, _) => "The first letter cannot be a prefix, so it is the root, followed by two suffixes.",
+		(_, 0) if ambiguous => "Less likely: the first letter as the root, without a prefix.",
+		(_, _) if ambiguous => "Less likely: a prefix before the root.",
+		(_, _) => "In a four-letter syllable the second letter is the root, between a prefix and two suffixes.",
+	}
+}
+
+/// Parses a word written in Tibetan Unicode, such as "སངས་རྒྱས་", with its syllables separated by tsheg.
+/// The traditional reading of each syllable is used.
+pub fn parse_tibetan_word(text: &str) -> Result<Word, ParseError> {
+	let syllables: Vec<&str> = text
+		.trim()
+		.split(TSHEG_UNICODE_CODE_POINT)
+		.filter(|s| !s.trim().is_empty())
+		.collect();
+	if syllables.is_empty() {
+		return Err(ParseError::Empty);
+	}
+	let syllables = syllables
+		.into_iter()
+		.map(parse_tibetan)
+		.collect::<Result<_, _>>()?;
+	Ok(Word { syllables })
+}
+
+/// Parses a phrase written in Tibetan Unicode, with its words separated by spaces or shad.
+pub fn parse_tibetan_phrase(text: &str) -> Result<Phrase, ParseError> {
+	let words: Vec<&str> = text
+		.split(|c: char| c.is_whitespace() || c == SHAD_UNICODE_CODE_POINT)
+		.filter(|w| !w.trim_matches(TSHEG_UNICODE_CODE_POINT).is_empty())
+		.collect();
+	if words.is_empty() {
+		return Err(ParseError::Empty);
+	}
+	let words = words
+		.into_iter()
+		.map(parse_tibetan_word)
+		.collect::<Result<_, _>>()?;
+	Ok(Phrase { words })
+}
+
+// Group the characters of the text into stacks, each starting with a letter written on the line.
+// A letter of Sanskrit written as a letter with a subjoined ཧ or ཥ, like ག followed by ྷ, is read as a single letter.
+fn split_stacks(text: &str) -> Result<Vec<Stack>, ParseError> {
+	let mut stacks: Vec<Stack> = vec![];
+	for c in text.chars() {
+		if let Some(letter) = all_letters().find(|&t| t.unicode_code_point == c) {
+			stacks.push(Stack::new(vec![letter], None));
+		} else if let Some(letter) = all_letters().find(|&t| t.unicode_code_point_as_subscript == c)
+		{
+			match stacks.last_mut() {
+				Some(stack) if stack.vowel.is_none() && !stack.long_vowel => {
+					let above = stack.letters.last_mut().unwrap();
+					match decomposed_letter(above, letter) {
+						Some(composed) => *above = composed,
+						None => stack.letters.push(letter),
+					}
+				}
+				_ => return Err(ParseError::UnknownCharacter(c)),
+			}
+		} else if c == A_CHUNG_UNICODE_CODE_POINT {
+			match stacks.last_mut() {
+				Some(stack) if stack.vowel.is_none() && !stack.long_vowel => {
+					stack.long_vowel = true
+				}
+				_ => return Err(ParseError::MisplacedVowel(c)),
+			}
+		} else if let Some(vowel) =
+			long_vowel(c).or_else(|| VOWELS.iter().find(|&v| v.unicode_code_point == c))
+		{
+			match stacks.last_mut() {
+				Some(stack) if stack.vowel.is_none() => {
+					stack.vowel = Some(vowel);
+					stack.long_vowel |= long_vowel(c).is_some();
+				}
+				_ => return Err(ParseError::MisplacedVowel(c)),
+			}
+		} else if let Some(mark) = SANSKRIT_MARKS.iter().find(|&m| m.unicode_code_point == c) {
+			match stacks.last_mut() {
+				Some(stack) if stack.mark.is_none() => stack.mark = Some(mark),
+				_ => return Err(ParseError::UnknownCharacter(c)),
+			}
+		} else {
+			return Err(ParseError::UnknownCharacter(c));
+		}
+	}
+	Ok(stacks)
+}
+
+// The letter of Sanskrit that a letter & the letter subjoined to it are equivalent to, such as གྷ for ག & ཧ.
+fn decomposed_letter(
+	letter: &TibetanCharacter,
+	subjoined: &TibetanCharacter,
+) -> Option<&'static TibetanCharacter> {
+	let (_, _, composed) = SANSKRIT_DECOMPOSITIONS
+		.iter()
+		.find(|(root, subscript, _)| *root == letter.tibetan && *subscript == subjoined.tibetan)?;
+	all_letters().find(|t| t.tibetan == *composed)
+}
+
+// The vowel of the signs for a long "i" & "u", which combine an a-chung with the vowel sign.
+fn long_vowel(c: char) -> Option<&'static TibetanVowel> {
+	let vowel = match c {
+		'\u{0F73}' => 'ི',
+		'\u{0F75}' => 'ུ',
+		_ => return None,
+	};
+	VOWELS.iter().find(|v| v.tibetan == vowel)
+}
+
+// Build a syllable of Sanskrit from its stacks, the last one being the root stack.
+// Only stacks spelled as Sanskrit are read this way, so that a syllable like ཀཀ is still rejected.
+fn sanskrit_syllable(stacks: &[Stack]) -> Option<TibetanSyllable> {
+	let (root_stack, preceding_stacks) = stacks.split_last()?;
+	if preceding_stacks.is_empty() || !is_sanskrit_spelling(stacks) {
+		return None;
+	}
+	let mut syllable = syllable_with_root_at(std::slice::from_ref(root_stack), 0).ok()?;
+	syllable.preceding_stacks = preceding_stacks.to_vec();
+	Some(syllable)
+}
+
+/// Splits a syllable of Sanskrit into the syllables of its customary Tibetan reading, one for each stack,
+/// e.g. ཏན & ཏྲ for ཏནྟྲ. The top letter of a stack is read as the suffix of the syllable before it,
+/// when it may be a suffix.
+pub fn customary_syllables(syllable: &TibetanSyllable) -> Vec<TibetanSyllable> {
+	if syllable.preceding_stacks.is_empty() {
+		return vec![syllable.clone()];
+	}
+	let mut stacks = syllable.preceding_stacks.clone();
+	stacks.push(syllable.root_stack());
+	let mut syllables = vec![];
+	for i in 0..stacks.len() {
+		let closing_letter = stacks
+			.get(i + 1)
+			.filter(|s| s.letters.len() > 1 && SUFFIXES.contains(&s.letters[0].tibetan))
+			.map(|s| Stack::new(vec![s.letters[0]], None));
+		if closing_letter.is_some() {
+			stacks[i + 1].letters.remove(0);
+		}
+		let stack = std::iter::once(stacks[i].clone()).chain(closing_letter);
+		match syllable_with_root_at(&stack.collect::<Vec<Stack>>(), 0) {
+			Ok(s) => syllables.push(s),
+			Err(_) => return vec![syllable.clone()],
+		}
+	}
+	if let Some(last) = syllables.last_mut() {
+		last.suffix = syllable.suffix;
+		last.second_suffix = syllable.second_suffix;
+		last.particle = syllable.particle;
+	}
+	syllables
+}
+
+// Returns whether a sequence of stacks is spelled as Sanskrit: with a stack after the first one,
+// or with a letter, long vowel or mark of Sanskrit.
+fn is_sanskrit_spelling(stacks: &[Stack]) -> bool {
+	let sanskrit_letter =
+		|c: &&TibetanCharacter| SANSKRIT_ROOTS.iter().any(|r| r.tibetan == c.tibetan);
+	stacks.iter().skip(1).any(|s| s.letters.len() > 1)
+		|| stacks
+			.iter()
+			.any(|s| s.long_vowel || s.mark.is_some() || s.letters.iter().any(sanskrit_letter))
+}
+
+// Build the syllable in which the stack at the given index is the root,
+// checking that the letters around it can be a prefix & suffixes.
+fn syllable_with_root_at(stacks: &[Stack], index: usize) -> Result<TibetanSyllable, ParseError> {
+	let text: String = stacks.iter().map(|s| s.tibetan()).collect();
+	// A stack that does not fit the slots of a native syllable is read as a stack of Sanskrit.
+	let ((superscript, root, subscript, wa_zur), subjoined) = match split_root_stack(&stacks[index])
+	{
+		Ok(root_stack) => (root_stack, vec![]),
+		Err(_) => split_sanskrit_stack(&stacks[index])?,
+	};
+
+	let before = &stacks[..index];
+	let after = &stacks[index + 1..];
+	if before.len() > 1 || after.len() > 2 || before.iter().chain(after).any(|s| s.is_marked()) {
+		return Err(ParseError::InvalidSyllable(text));
+	}
+
+	let prefix = before.first().map(|s| s.letters[0]);
+	let suffix = after.first().map(|s| s.letters[0]);
+	let second_suffix = after.get(1).map(|s| s.letters[0]);
+	// A stack of Sanskrit takes no prefix, so a letter before it is read as a stack of its own, as in པདྨ.
+	if prefix.is_some_and(|p| !PREFIXES.contains(&p.tibetan) || !subjoined.is_empty())
+		|| suffix.is_some_and(|s| !SUFFIXES.contains(&s.tibetan))
+		|| second_suffix.is_some_and(|s| !SECOND_SUFFIXES.contains(&s.tibetan))
+	{
+		return Err(ParseError::InvalidSyllable(text));
+	}
+
+	Ok(TibetanSyllable {
+		root,
+		prefix,
+		superscript,
+		subscript,
+		wa_zur,
+		subjoined,
+		preceding_stacks: vec![],
+		vowel: stacks[index].vowel,
+		suffix,
+		second_suffix,
+		particle: None,
+		long_vowel: stacks[index].long_vowel,
+		mark: stacks[index].mark,
+	})
+}
+
+/// A single token of an Extended Wylie syllable.
+#[derive(Clone, Copy)]
+enum WylieToken {
+	Consonant(&'static TibetanCharacter),
+	// The inherent "a" is represented by `None`, and a vowel written in capitals, such as "A", is long.
+	Vowel(Option<&'static TibetanVowel>, bool),
+	// A mark of Sanskrit, such as the anusvara "M".
+	Mark(&'static SanskritMark),
+	// The "." disambiguator, which separates a prefix from the root, as in "g.yag".
+	Dot,
+	// The "+" joining two letters into a stack, as in "g+ya".
+	Plus,
+}
+
+/// Parses a single syllable written in Extended Wylie (EWTS), such as "bsgrubs" or "g.yag".
+pub fn parse_wylie(text: &str) -> Result<TibetanSyllable, ParseError> {
+	let text = text.trim();
+	if text.is_empty() {
+		return Err(ParseError::Empty);
+	}
+
+	// A contracted particle is read as the suffix 'a of an open syllable, which it then replaces,
+	// so "po'i" is read as "po'" followed by the genitive particle.
+	for particle in CONTRACTED_PARTICLES.iter() {
+		if !text.ends_with(particle.wylie) || text.len() == particle.wylie.len() {
+			continue;
+		}
+		let open_syllable = &text[..text.len() - particle.wylie.len() + 1];
+		if let Ok(mut syllable) = parse_wylie_without_particle(open_syllable) {
+			if syllable.suffix.map(|s| s.tibetan) == Some('འ') && syllable.second_suffix.is_none()
+			{
+				syllable.suffix = None;
+				syllable.particle = Some(particle);
+				return Ok(syllable);
+			}
+		}
+	}
+	parse_wylie_without_particle(text)
+}
+
+fn parse_wylie_without_particle(text: &str) -> Result<TibetanSyllable, ParseError> {
+	let tokens = tokenize_wylie(text)?;
+	// A "+" joins two letters into a stack, so it stands between them, as in "g+ya" but not "g.+ya".
+	let consonant = |i: usize| matches!(tokens.get(i), Some(WylieToken::Consonant(_)));
+	let stray_plus = tokens.iter().enumerate().any(|(i, t)| {
+		matches!(t, WylieToken::Plus) && (i == 0 || !consonant(i - 1) || !consonant(i + 1))
+	});
+	if stray_plus {
+		return Err(ParseError::InvalidStack(text.to_owned()));
+	}
+	// A vowel after a suffix 'a is a contracted particle, as in "po'i".
+	let contracted = tokens.windows(3).any(|w| match w {
+		[WylieToken::Vowel(..), WylieToken::Consonant(c), WylieToken::Vowel(..)] => {
+			c.tibetan == 'འ'
+		}
+		_ => false,
+	});
+	if contracted {
+		return Err(ParseError::UnsupportedContraction(text.to_owned()));
+	}
+	let (preceding_stacks, tokens) = split_wylie_preceding_stacks(text, &tokens)?;
+
+	// The first vowel splits the syllable into the letters above the line & the suffixes.
+	let vowel_index = tokens
+		.iter()
+		.position(|t| matches!(t, WylieToken::Vowel(..)))
+		.ok_or_else(|| ParseError::MissingVowel(text.to_owned()))?;
+	let (vowel, long_vowel) = match tokens[vowel_index] {
+		WylieToken::Vowel(vowel, long) => (vowel, long),
+		_ => unreachable!(),
+	};
+
+	// A mark directly follows the vowel.
+	let mut rest = &tokens[vowel_index + 1..];
+	let mark = match rest.first() {
+		Some(WylieToken::Mark(mark)) => {
+			rest = &rest[1..];
+			Some(*mark)
+		}
+		_ => None,
+	};
+
+	let mut suffixes: Vec<&'static TibetanCharacter> = vec![];
+	for token in rest {
+		match token {
+			WylieToken::Consonant(c) => suffixes.push(c),
+			_ => return Err(ParseError::InvalidSyllable(text.to_owned())),
+		}
+	}
+
+	// Letters joined with "+" that do not fit the slots of a native syllable are read as a stack of Sanskrit.
+	let (prefix, stack) = split_wylie_onset(text, &tokens[..vowel_index])?;
+	let stack = Stack::new(stack, vowel);
+	let joined = tokens[..vowel_index]
+		.iter()
+		.any(|t| matches!(t, WylieToken::Plus));
+	let ((superscript, root, subscript, wa_zur), subjoined) = match split_root_stack(&stack) {
+		Ok(root_stack) => (root_stack, vec![]),
+		Err(_) if joined => split_sanskrit_stack(&stack)?,
+		Err(e) => return Err(e),
+	};
+	let suffix = suffixes.first().copied();
+	let second_suffix = suffixes.get(1).copied();
+	// A stack of Sanskrit takes no prefix, and only a syllable spelled as Sanskrit has several stacks.
+	let mut stacks = preceding_stacks.clone();
+	stacks.push(Stack {
+		long_vowel,
+		mark,
+		..stack
+	});
+	if suffixes.len() > 2
+		|| (!preceding_stacks.is_empty() && (prefix.is_some() || !is_sanskrit_spelling(&stacks)))
+		|| prefix.is_some_and(|p| !PREFIXES.contains(&p.tibetan) || !subjoined.is_empty())
+		|| suffix.is_some_and(|s| !SUFFIXES.contains(&s.tibetan))
+		|| second_suffix.is_some_and(|s| !SECOND_SUFFIXES.contains(&s.tibetan))
+	{
+		return Err(ParseError::InvalidSyllable(text.to_owned()));
+	}
+
+	Ok(TibetanSyllable {
+		root,
+		prefix,
+		superscript,
+		subscript,
+		wa_zur,
+		subjoined,
+		preceding_stacks,
+		vowel,
+		suffix,
+		second_suffix,
+		particle: None,
+		long_vowel,
+		mark,
+	})
+}
+
+// Split off the stacks of Sanskrit written before the root stack, each closed by its own vowel, as in "pad+ma",
+// returning them with the tokens of the rest of the syllable.
+// Every vowel but the last closes such a stack, whose letters must be joined with "+" unless they make up a native stack.
+fn split_wylie_preceding_stacks<'a>(
+	text: &str,
+	tokens: &'a [WylieToken],
+) -> Result<(Vec<Stack>, &'a [WylieToken]), ParseError> {
+	let invalid = || ParseError::InvalidSyllable(text.to_owned());
+	let mut stacks = vec![];
+	let mut rest = tokens;
+	while let Some(vowel_index) = rest
+		.iter()
+		.position(|t| matches!(t, WylieToken::Vowel(..)))
+		.filter(|&i| {
+			rest[i + 1..]
+				.iter()
+				.any(|t| matches!(t, WylieToken::Vowel(..)))
+		}) {
+		let mut letters = vec![];
+		for token in &rest[..vowel_index] {
+			match token {
+				WylieToken::Consonant(c) => letters.push(*c),
+				WylieToken::Plus => (),
+				_ => return Err(invalid()),
+			}
+		}
+		let joined = rest[..vowel_index]
+			.iter()
+			.any(|t| matches!(t, WylieToken::Plus));
+		match letters[..] {
+			[] if stacks.is_empty() => {
+				letters.push(ROOTS.iter().find(|&t| t.tibetan == 'ཨ').unwrap())
+			}
+			[] => return Err(invalid()),
+			[_] => (),
+			_ if joined || is_stack(&letters) => (),
+			_ => return Err(invalid()),
+		}
+		let (vowel, long_vowel) = match rest[vowel_index] {
+			WylieToken::Vowel(vowel, long) => (vowel, long),
+			_ => unreachable!(),
+		};
+		rest = &rest[vowel_index + 1..];
+		let mark = match rest.first() {
+			Some(WylieToken::Mark(mark)) => {
+				rest = &rest[1..];
+				Some(*mark)
+			}
+			_ => None,
+		};
+		stacks.push(Stack {
+			letters,
+			vowel,
+			long_vowel,
+			mark,
+		});
+	}
+	Ok((stacks, rest))
+}
+
+/// Parses a word written in Extended Wylie, such as "sangs rgyas", with its syllables separated by spaces.
+pub fn parse_wylie_word(text: &str) -> Result<Word, ParseError> {
+	Ok(Word {
+		syllables: parse_wylie_syllables(text)?,
+	})
+}
+
+/// Parses a sequence of syllables written in Extended Wylie, such as "sangs rgyas",
+/// separated by spaces (tsheg) or "/" (shad).
+pub fn parse_wylie_syllables(text: &str) -> Result<Vec<TibetanSyllable>, ParseError> {
+	let syllables: Vec<&str> = text
+		.split(|c: char| c.is_whitespace() || c == '/')
+		.filter(|s| !s.is_empty())
+		.collect();
+	if syllables.is_empty() {
+		return Err(ParseError::Empty);
+	}
+	syllables.into_iter().map(parse_wylie).collect()
+}
+
+// Split an Extended Wylie syllable into consonants, vowels & punctuation,
+// always matching the longest possible consonant, so that "tsh" is tsha rather than "ts" & "h".
+// The letters of Sanskrit are written with capitals & "h", as in "Ta" & "bha", as are the long vowels & marks.
+fn tokenize_wylie(text: &str) -> Result<Vec<WylieToken>, ParseError> {
+	let mut tokens = vec![];
+	let mut rest = text;
+	while let Some(c) = rest.chars().next() {
+		let token = match c {
+			'.' => Some((WylieToken::Dot, 1)),
+			'+' => Some((WylieToken::Plus, 1)),
+			'a' => Some((WylieToken::Vowel(None, false), 1)),
+			'A' => Some((WylieToken::Vowel(None, true), 1)),
+			_ => VOWELS
+				.iter()
+				.find(|v| rest.starts_with(v.wylie))
+				.map(|v| (WylieToken::Vowel(Some(v), false), v.wylie.len()))
+				.or_else(|| {
+					VOWELS
+						.iter()
+						.filter(|v| v.wylie == "i" || v.wylie == "u")
+						.find(|v| rest.starts_with(&v.wylie.to_uppercase()))
+						.map(|v| (WylieToken::Vowel(Some(v), true), v.wylie.len()))
+				})
+				.or_else(|| {
+					SANSKRIT_MARKS
+						.iter()
+						.find(|m| rest.starts_with(m.wylie))
+						.map(|m| (WylieToken::Mark(m), m.wylie.len()))
+				})
+				.or_else(|| {
+					all_letters()
+						.filter(|r| {
+							!r.wylie_consonant().is_empty() && rest.starts_with(r.wylie_consonant())
+						})
+						.max_by_key(|r| r.wylie_consonant().len())
+						.map(|r| (WylieToken::Consonant(r), r.wylie_consonant().len()))
+				}),
+		};
+		match token {
+			Some((token, length)) => {
+				tokens.push(token);
+				rest = &rest[length..];
+			}
+			None => return Err(ParseError::UnknownCharacter(c)),
+		}
+	}
+	Ok(tokens)
+}
+
+// Split the letters before the vowel into an optional prefix & the root stack.
+// A syllable without any letters before the vowel has ཨ as its root.
+// Without a "." the letters are read as a single stack whenever possible, so "gya" is གྱ,
+// and otherwise the first letter is read as a prefix, so "dka" is དཀ.
+fn split_wylie_onset(
+	text: &str,
+	tokens: &[WylieToken],
+) -> Result<
+	(
+		Option<&'static TibetanCharacter>,
+		Vec<&'static TibetanCharacter>,
+	),
+	ParseError,
+> {
+	let invalid = || ParseError::InvalidSyllable(text.to_owned());
+	let letters = |tokens: &[WylieToken]| -> Vec<&'static TibetanCharacter> {
+		tokens
+			.iter()
+			.filter_map(|t| match t {
+				WylieToken::Consonant(c) => Some(*c),
+				_ => None,
+			})
+			.collect()
+	};
+
+	if let Some(dot) = tokens.iter().position(|t| matches!(t, WylieToken::Dot)) {
+		return match letters(&tokens[..dot])[..] {
+			[prefix] if dot == 1 => Ok((Some(prefix), letters(&tokens[dot + 1..]))),
+			_ => Err(invalid()),
+		};
+	}
+
+	let onset = letters(tokens);
+	if onset.is_empty() {
+		return Ok((
+			None,
+			vec![ROOTS.iter().find(|&t| t.tibetan == 'ཨ').unwrap()],
+		));
+	}
+	// A "+" after the first letter joins it to the stack, as in "g+ya", while a later one
+	// only joins the letters after the prefix.
+	let joined_to_second = matches!(tokens.get(1), Some(WylieToken::Plus));
+	if is_stack(&onset) || joined_to_second || onset.len() == 1 {
+		Ok((None, onset))
+	} else {
+		Ok((Some(onset[0]), onset[1..].to_vec()))
+	}
+}
+
+/// Returns whether the letters, from top to bottom, can be read as a single stack
+/// of an optional superscript, a root & an optional subscript.
+pub fn is_stack(letters: &[&'static TibetanCharacter]) -> bool {
+	split_root_stack(&Stack::new(letters.to_vec(), None)).is_ok()
+}
+
+// The superscript, root & subscript of a root stack.
+type RootStack = (
+	Option<&'static TibetanCharacter>,
+	&'static TibetanCharacter,
+	Option<&'static TibetanCharacter>,
+	bool,
+);
+
+// Split a stack into its superscript, root, subscript & wa-zur.
+// A letter that may be subscribed to the one above it is read as a subscript,
+// otherwise the top letter of the stack must be a superscript.
+// A wa at the bottom of the stack is a wa-zur, when the letters above it may take one.
+fn split_root_stack(stack: &Stack) -> Result<RootStack, ParseError> {
+	let can_subscribe = |root: &TibetanCharacter, subscript: &TibetanCharacter| {
+		root.available_subscripts().contains(&subscript.tibetan)
+	};
+	let is_superscript = |c: &TibetanCharacter| SUPERSCRIPTS.contains(&c.tibetan);
+	let invalid = || ParseError::InvalidStack(stack.tibetan());
+
+	let (letters, wa_zur) = match stack.letters.split_last() {
+		Some((last, above)) if last.tibetan == 'ཝ' && !above.is_empty() => (above, true),
+		_ => (&stack.letters[..], false),
+	};
+	let (superscript, root, subscript) = match *letters {
+		[root] => (None, root, None),
+		[root, subscript] if can_subscribe(root, subscript) => (None, root, Some(subscript)),
+		[superscript, root] if is_superscript(superscript) => (Some(superscript), root, None),
+		[superscript, root, subscript]
+			if is_superscript(superscript) && can_subscribe(root, subscript) =>
+		{
+			(Some(superscript), root, Some(subscript))
+		}
+		_ => return Err(invalid()),
+	};
+	if wa_zur && !root.takes_wa_zur(subscript) {
+		return Err(invalid());
+	}
+	Ok((superscript, root, subscript, wa_zur))
+}
+
+// Split a stack of Sanskrit, such as ནྟ or རྐྵ, into its superscript, root & subscript,
+// followed by the letters subjoined below them, from top to bottom.
+// A superscript is read only over another letter, and a subscript only where it may be subscribed to the root.
+fn split_sanskrit_stack(
+	stack: &Stack,
+) -> Result<(RootStack, Vec<&'static TibetanCharacter>), ParseError> {
+	let mut letters = stack.letters.iter().copied();
+	let mut root = letters
+		.next()
+		.ok_or_else(|| ParseError::InvalidStack(stack.tibetan()))?;
+	let mut superscript = None;
+	if SUPERSCRIPTS.contains(&root.tibetan) && stack.letters.len() > 1 {
+		superscript = Some(root);
+		root = letters.next().unwrap();
+	}
+	let mut subjoined: Vec<&'static TibetanCharacter> = letters.collect();
+	let subscript = match subjoined.first() {
+		Some(letter) if root.available_subscripts().contains(&letter.tibetan) => {
+			Some(subjoined.remove(0))
+		}
+		_ => None,
+	};
+	Ok(((superscript, root, subscript, false), subjoined))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::*;
+	use super::*;
+
+	#[test]
+	fn parse_stacked_syllable() {
+		let syllable = parse_tibetan("བསྒྲུབས་").unwrap();
+
+		assert_eq!('བ', syllable.prefix.unwrap().tibetan);
+		assert_eq!('ས', syllable.superscript.unwrap().tibetan);
+		assert_eq!('ག', syllable.root.tibetan);
+		assert_eq!('ར', syllable.subscript.unwrap().tibetan);
+		assert_eq!('ུ', syllable.vowel.unwrap().tibetan);
+		assert_eq!('བ', syllable.suffix.unwrap().tibetan);
+		assert_eq!('ས', syllable.second_suffix.unwrap().tibetan);
+		assert_eq!("བསྒྲུབས", tibetan(&syllable));
+	}
+
+	#[test]
+	fn parse_unstacked_syllable() {
+		let syllable = parse_tibetan("དགར").unwrap();
+
+		assert_eq!('ད', syllable.prefix.unwrap().tibetan);
+		assert_eq!('ག', syllable.root.tibetan);
+		assert_eq!('ར', syllable.suffix.unwrap().tibetan);
+	}
+
+	#[test]
+	fn parse_ambiguous_syllables() {
+		let readings = parse_tibetan_readings("དགས").unwrap();
+		assert_eq!(2, readings.len());
+		assert_eq!('ག', readings[0].syllable.root.tibetan);
+		assert_eq!('ད', readings[1].syllable.root.tibetan);
+
+		// The reading with two suffixes is misspelled, since sa may not follow the suffix da.
+		let readings = parse_tibetan_readings("མདས").unwrap();
+		assert_eq!(1, readings.len());
+		assert_eq!("mdas", wylie(&readings[0].syllable));
+
+		let readings = parse_tibetan_readings("དག").unwrap();
+		assert_eq!(2, readings.len());
+		assert_eq!('ད', readings[0].syllable.root.tibetan);
+		assert_eq!('ག', readings[0].syllable.suffix.unwrap().tibetan);
+	}
+
+	#[test]
+	fn parse_ga_prefix_before_ya() {
+		let syllable = parse_tibetan("གཡག").unwrap();
+		assert_eq!('ག', syllable.prefix.unwrap().tibetan);
+		assert_eq!('ཡ', syllable.root.tibetan);
+		assert!(syllable.subscript.is_none());
+
+		let syllable = parse_tibetan("གྱག").unwrap();
+		assert!(syllable.prefix.is_none());
+		assert_eq!('ག', syllable.root.tibetan);
+		assert_eq!('ཡ', syllable.subscript.unwrap().tibetan);
+	}
+
+	#[test]
+	fn parse_wylie_syllable() {
+		let syllable = parse_wylie("bsgrubs").unwrap();
+		assert_eq!("བསྒྲུབས", tibetan(&syllable));
+
+		let syllable = parse_wylie("g.yag").unwrap();
+		assert_eq!('ག', syllable.prefix.unwrap().tibetan);
+		assert_eq!('ཡ', syllable.root.tibetan);
+		assert_eq!("གཡག", tibetan(&syllable));
+
+		assert_eq!("གྱག", tibetan(&parse_wylie("gyag").unwrap()));
+		assert_eq!("གྱག", tibetan(&parse_wylie("g+yag").unwrap()));
+		assert_eq!("དགའ", tibetan(&parse_wylie("dga'").unwrap()));
+		assert_eq!("འགྲོ", tibetan(&parse_wylie("'gro").unwrap()));
+		assert_eq!("ཚོགས", tibetan(&parse_wylie("tshogs").unwrap()));
+		assert_eq!("ཨོམ", tibetan(&parse_wylie("om").unwrap()));
+		assert_eq!("འི", tibetan(&parse_wylie("'i").unwrap()));
+		assert_eq!("འམ", tibetan(&parse_wylie("'am").unwrap()));
+	}
+
+	#[test]
+	fn parse_contracted_particles() {
+		let syllable = parse_wylie("po'i").unwrap();
+		assert_eq!("པོའི", tibetan(&syllable));
+		assert!(syllable.suffix.is_none());
+		assert_eq!("'i", syllable.particle.unwrap().wylie);
+
+		let syllable = parse_wylie("dga'am").unwrap();
+		assert_eq!('ད', syllable.prefix.unwrap().tibetan);
+		assert_eq!("འམ", syllable.particle.unwrap().tibetan);
+
+		let syllable = parse_tibetan("དགའི").unwrap();
+		assert_eq!('ད', syllable.prefix.unwrap().tibetan);
+		assert_eq!('ག', syllable.root.tibetan);
+		assert_eq!("dga'i", wylie(&syllable));
+
+		let syllable = parse_tibetan("པོའང").unwrap();
+		assert_eq!("po'ang", wylie(&syllable));
+
+		assert_eq!(
+			ParseError::UnsupportedContraction("bu'u".to_owned()),
+			parse_wylie("bu'u").unwrap_err()
+		);
+	}
+
+	#[test]
+	fn parse_wylie_sequence() {
+		let syllables = parse_wylie_syllables("sangs rgyas/").unwrap();
+		let tibetan: Vec<String> = syllables.iter().map(tibetan).collect();
+		assert_eq!(vec!["སངས", "རྒྱས"], tibetan);
+	}
+
+	#[test]
+	fn parse_words() {
+		let word = parse_tibetan_word("སངས་རྒྱས་").unwrap();
+		assert_eq!(2, word.syllables.len());
+
+		let phrase = parse_tibetan_phrase("སངས་རྒྱས་ ཆོས་།").unwrap();
+		assert_eq!(2, phrase.words.len());
+		assert_eq!(1, phrase.words[1].syllables.len());
+
+		let word = parse_wylie_word("sangs rgyas").unwrap();
+		assert_eq!("རྒྱས", tibetan(&word.syllables[1]));
+	}
+
+	#[test]
+	fn parse_invalid_wylie() {
+		assert_eq!(
+			ParseError::MissingVowel("bsgr".to_owned()),
+			parse_wylie("bsgr").unwrap_err()
+		);
+		assert_eq!(
+			ParseError::UnknownCharacter('q'),
+			parse_wylie("qa").unwrap_err()
+		);
+		assert_eq!(
+			ParseError::InvalidSyllable("kaka".to_owned()),
+			parse_wylie("kaka").unwrap_err()
+		);
+	}
+
+	#[test]
+	fn parse_sanskrit_letters() {
+		// ཀྵ & གྷ may be written with a single code point, or as a letter with a subjoined ཥ or ཧ.
+		assert_eq!('\u{0F69}', parse_tibetan("\u{0F69}").unwrap().root.tibetan);
+		assert_eq!('\u{0F69}', parse_tibetan("ཀ\u{0FB5}").unwrap().root.tibetan);
+		assert_eq!('\u{0F43}', parse_tibetan("ག\u{0FB7}").unwrap().root.tibetan);
+
+		let syllable = parse_tibetan("ཧ\u{0F75}").unwrap();
+		assert!(syllable.long_vowel);
+		assert_eq!('ུ', syllable.vowel.unwrap().tibetan);
+
+		let syllable = parse_wylie("AH").unwrap();
+		assert_eq!('ཨ', syllable.root.tibetan);
+		assert!(syllable.long_vowel);
+		assert_eq!('ཿ', syllable.mark.unwrap().tibetan);
+		assert_eq!("\u{0F69}", tibetan(&parse_wylie("kSha").unwrap()));
+	}
+
+	#[test]
+	fn parse_invalid_input() {
+		assert_eq!(ParseError::Empty, parse_tibetan(" ་").unwrap_err());
+		assert_eq!(
+			ParseError::UnknownCharacter('k'),
+			parse_tibetan("ཀk").unwrap_err()
+		);
+		assert_eq!(
+			ParseError::InvalidStack("ཟྡ".to_owned()),
+			parse_wylie("bzda").unwrap_err()
+		);
+		for text in &["g.+a", "+ka", "k++a", "ka+"] {
+			assert_eq!(
+				ParseError::InvalidStack(text.to_string()),
+				parse_wylie(text).unwrap_err()
+			);
+		}
+	}
+
+	#[test]
+	fn parse_wa_zur() {
+		let syllable = parse_tibetan("གྲྭ").unwrap();
+		assert_eq!('ག', syllable.root.tibetan);
+		assert_eq!('ར', syllable.subscript.unwrap().tibetan);
+		assert!(syllable.wa_zur);
+		assert!(syllable.subjoined.is_empty());
+		assert_eq!("grwa", wylie(&syllable));
+
+		assert_eq!("རྩྭ", tibetan(&parse_wylie("rtswa").unwrap()));
+		assert_eq!("ཚྭ", tibetan(&parse_wylie("tshwa").unwrap()));
+		assert_eq!("ཀྭ", tibetan(&parse_wylie("kwa").unwrap()));
+		assert_eq!("ཕྱྭ", tibetan(&parse_wylie("phywa").unwrap()));
+		assert!(parse_tibetan("སྒྲྭ").is_ok_and(|s| s.wa_zur));
+
+		let syllable = parse_tibetan("དྲྭ").unwrap();
+		assert!(syllable.wa_zur);
+		assert!(syllable.subjoined.is_empty());
+		assert_eq!("drwa", wylie(&syllable));
+		assert_eq!("དྲྭ", tibetan(&parse_wylie("drwa").unwrap()));
+	}
+
+	#[test]
+	fn parse_sanskrit_stacks() {
+		let syllable = parse_tibetan("ནྟ").unwrap();
+		assert_eq!('ན', syllable.root.tibetan);
+		assert!(syllable.subscript.is_none());
+		assert_eq!(
+			vec!['ཏ'],
+			syllable
+				.subjoined
+				.iter()
+				.map(|c| c.tibetan)
+				.collect::<Vec<_>>()
+		);
+		assert_eq!("n+ta", wylie(&syllable));
+
+		let syllable = parse_tibetan("ཎྜྭ").unwrap();
+		assert_eq!('ཎ', syllable.root.tibetan);
+		assert_eq!(
+			vec!['ཌ', 'ཝ'],
+			syllable
+				.subjoined
+				.iter()
+				.map(|c| c.tibetan)
+				.collect::<Vec<_>>()
+		);
+		assert_eq!("ཎྜྭ", tibetan(&syllable));
+
+		assert_eq!("ཛྲ", tibetan(&parse_wylie("dzra").unwrap()));
+	}
+
+	#[test]
+	fn parse_sanskrit_words() {
+		// A letter before a stack of Sanskrit is read as a stack of its own, with its own vowel.
+		let syllable = parse_tibetan("པདྨེ").unwrap();
+		assert!(syllable.prefix.is_none());
+		assert_eq!(1, syllable.preceding_stacks.len());
+		assert_eq!('ད', syllable.root.tibetan);
+		assert_eq!("pad+me", wylie(&syllable));
+
+		let words = [
+			("པདྨ", "pad+ma"),
+			("\u{0F52}རྨ", "dhar+ma"),
+			("ཀརྨ", "kar+ma"),
+			("བུདྡ\u{0FB7}", "bud+dha"),
+			("སིདྡ\u{0FB7}ི", "sid+dhi"),
+			("ཝཛྲ", "wadzra"),
+			("ཏནྟྲ", "tan+t+ra"),
+			("མནྟྲ", "man+t+ra"),
+		];
+		for (text, expected) in words {
+			let syllable = parse_tibetan(text).unwrap();
+			assert_eq!(expected, wylie(&syllable));
+			assert_eq!(tibetan(&syllable), tibetan(&parse_wylie(expected).unwrap()));
+			assert_eq!(expected, wylie(&parse_wylie(expected).unwrap()));
+		}
+
+		assert_eq!(
+			ParseError::InvalidSyllable("pd+ma".to_owned()),
+			parse_wylie("pd+ma").unwrap_err()
+		);
+		assert_eq!(
+			ParseError::InvalidSyllable("ཀཀ".to_owned()),
+			parse_tibetan("ཀཀ").unwrap_err()
+		);
+	}
+
+	#[test]
+	fn split_customary_syllables() {
+		let words = [
+			("ཏནྟྲ", vec!["tan", "tra"]),
+			("ཀརྨ", vec!["kar", "ma"]),
+			("བུདྡ\u{0FB7}", vec!["bud", "dha"]),
+			("ཝཛྲ", vec!["wa", "dzra"]),
+			("ཨོཾ", vec!["oM"]),
+			("བསྒྲུབས", vec!["bsgrubs"]),
+		];
+		for (text, expected) in words.iter() {
+			let syllables = customary_syllables(&parse_tibetan(text).unwrap());
+			let syllables: Vec<String> = syllables.iter().map(wylie).collect();
+			assert_eq!(expected, &syllables);
+		}
+	}
+}
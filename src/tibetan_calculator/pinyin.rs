@@ -0,0 +1,108 @@
+use super::tibetan_data::*;
+use super::Pronunciation;
+
+/// The onsets that Tibetan Pinyin writes differently from the calculator's own scheme.
+/// Like Chinese Pinyin, it writes unaspirated initials with the letters of voiced ones, e.g. "g" for "k".
+const PINYIN_ONSETS: [(&str, &str); 24] = [
+	("k", "g"),
+	("kh", "k"),
+	("ky", "gy"),
+	("khy", "ky"),
+	("c", "j"),
+	("ch", "q"),
+	("t", "d"),
+	("th", "t"),
+	("p", "b"),
+	("ph", "p"),
+	("ts", "z"),
+	("tsh", "c"),
+	("dz", "z"),
+	("tr", "zh"),
+	("thr", "ch"),
+	("dr", "zh"),
+	("hr", "sh"),
+	("zh", "x"),
+	("sh", "x"),
+	("z", "s"),
+	("g", "g"),
+	("j", "j"),
+	("d", "d"),
+	("b", "b"),
+];
+
+/// The final consonants that Tibetan Pinyin writes differently from the calculator's own scheme.
+/// A final "l" only changes the vowel, and is not written.
+const PINYIN_CODAS: [(&str, &str); 3] = [("k", "g"), ("p", "b"), ("l", "")];
+
+const CIRCUMFLEX_UNICODE_CODE_POINT: char = '\u{0302}';
+
+// Writes the pronunciation of a syllable in the official Tibetan Pinyin of the PRC, e.g. "zê" for རྩེ.
+pub(super) fn pinyin(pronunciation: &Pronunciation) -> String {
+	let mut result = match PINYIN_ONSETS
+		.iter()
+		.find(|(onset, _)| *onset == pronunciation.onset)
+	{
+		Some((_, onset)) => String::from(*onset),
+		None => pronunciation.onset.clone(),
+	};
+	// A changed "a" & "o" are written "ai" & "oi", and "e" is always written "ê".
+	match (pronunciation.vowel, pronunciation.umlaut) {
+		('a', true) => result.push_str("ai"),
+		('o', true) => result.push_str("oi"),
+		('e', _) => {
+			result.push('e');
+			result.push(CIRCUMFLEX_UNICODE_CODE_POINT);
+		}
+		(vowel, umlaut) => {
+			result.push(vowel);
+			if umlaut {
+				result.push(DIAIRESIS_UNICODE_CODE_POINT);
+			}
+		}
+	}
+	match PINYIN_CODAS
+		.iter()
+		.find(|(coda, _)| *coda == pronunciation.coda)
+	{
+		Some((_, coda)) => result.push_str(coda),
+		None => result.push_str(pronunciation.coda),
+	}
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::parser::*;
+	use super::super::*;
+
+	fn pinyin(text: &str) -> String {
+		let options = PhoneticOptions {
+			scheme: PhoneticScheme::Pinyin,
+			tone: ToneNotation::Accents,
+			..PhoneticOptions::default()
+		};
+		phonetic_word(&parse_tibetan_word(text).unwrap(), &options)
+	}
+
+	#[test]
+	fn syllables() {
+		assert_eq!("ga", pinyin("ཀ"));
+		assert_eq!("ka", pinyin("ཁ"));
+		assert_eq!("qo", pinyin("ཇོ"));
+		assert_eq!("xi", pinyin("གཞིས"));
+		assert_eq!("zê", pinyin("རྩེ"));
+		assert_eq!("gyai", pinyin("རྒྱལ"));
+		assert_eq!("qoi", pinyin("ཆོས"));
+		assert_eq!("poi", pinyin("བོད"));
+		assert_eq!("gün", pinyin("ཀུན"));
+	}
+
+	#[test]
+	fn words() {
+		assert_eq!("xigazê", pinyin("གཞིས་ཀ་རྩེ"));
+		assert_eq!("qomolangma", pinyin("ཇོ་མོ་གླང་མ"));
+		assert_eq!("namco", pinyin("གནམ་མཚོ"));
+		assert_eq!("nagqu", pinyin("ནག་ཆུ"));
+		assert_eq!("dêqên", pinyin("བདེ་ཆེན"));
+	}
+}
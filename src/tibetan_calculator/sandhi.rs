@@ -0,0 +1,61 @@
+use super::tibetan_data::*;
+
+/// The prefixes that nasalise the end of the previous syllable.
+pub const NASAL_PREFIXES: [char; 2] = ['མ', 'འ'];
+
+/// Applies the sandhi of standard Lhasa reading to a syllable that is not the first of its word,
+/// given the previous syllable.
+/// Returns any nasal that should close the previous syllable, & the changed onset of the syllable.
+pub fn apply_sandhi(
+	previous: &TibetanSyllable,
+	syllable: &TibetanSyllable,
+	onset: String,
+) -> (&'static str, String) {
+	let previous_is_open = match (previous.suffix, previous.particle) {
+		(_, Some(particle)) => !particle.closes_syllable,
+		(Some(suffix), None) => suffix.tibetan == 'འ',
+		(None, None) => true,
+	};
+	let onset_is_bare = syllable.prefix.is_none() && syllable.superscript.is_none();
+
+	// A nasal prefix nasalises an open previous syllable, with a nasal matching the following root,
+	// e.g. བཀའ་འགྱུར "kangyur".
+	let nasal = match syllable.prefix {
+		Some(prefix) if previous_is_open && NASAL_PREFIXES.contains(&prefix.tibetan) => {
+			match syllable.root.tibetan {
+				'ཀ' | 'ཁ' | 'ག' | 'ང' => "ng",
+				'པ' | 'ཕ' | 'བ' | 'མ' => "m",
+				_ => "n",
+			}
+		}
+		_ => "",
+	};
+
+	// A bare ba after an open syllable is pronounced "w", e.g. དགའ་བ "gawa".
+	if previous_is_open
+		&& onset_is_bare
+		&& syllable.subscript.is_none()
+		&& syllable.root.tibetan == 'བ'
+	{
+		return (nasal, replace_onset(&onset, "ph", "w"));
+	}
+
+	// The unaspirated low-tone initials of the third column are voiced within a word, e.g. ཡི་གེ "yige".
+	if onset_is_bare && syllable.root.column == Column::THIRD {
+		for (devoiced, voiced) in &[("kh", "g"), ("ch", "j"), ("th", "d"), ("ph", "b")] {
+			if onset.starts_with(devoiced) {
+				return (nasal, replace_onset(&onset, devoiced, voiced));
+			}
+		}
+	}
+
+	(nasal, onset)
+}
+
+// Replace the beginning of the onset of a syllable, if it matches.
+fn replace_onset(onset: &str, initial: &str, replacement: &str) -> String {
+	match onset.strip_prefix(initial) {
+		Some(rest) => format!("{}{}", replacement, rest),
+		None => onset.to_owned(),
+	}
+}
@@ -0,0 +1,345 @@
+use super::tibetan_data::*;
+
+/// A script into which Sanskrit written in Tibetan script can be transliterated.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Transliteration {
+	Devanagari,
+	/// The International Alphabet of Sanskrit Transliteration, e.g. "oṃ".
+	Iast,
+}
+
+pub const TRANSLITERATIONS: [Transliteration; 2] =
+	[Transliteration::Devanagari, Transliteration::Iast];
+
+impl std::fmt::Display for Transliteration {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Transliteration::Devanagari => write!(f, "Devanagari"),
+			Transliteration::Iast => write!(f, "IAST"),
+		}
+	}
+}
+
+/// The Sanskrit value of each Tibetan letter, in Devanagari & in IAST.
+/// Sanskrit "c", "ch", "j" & "jh" are traditionally written with ཙ, ཚ, ཛ & ཛྷ, but ཅ, ཆ & ཇ are read the same.
+const SANSKRIT_CONSONANTS: [(char, &str, &str); 38] = [
+	('ཀ', "क", "k"),
+	('ཁ', "ख", "kh"),
+	('ག', "ग", "g"),
+	('\u{0F43}', "घ", "gh"),
+	('ང', "ङ", "ṅ"),
+	('ཅ', "च", "c"),
+	('ཆ', "छ", "ch"),
+	('ཇ', "ज", "j"),
+	('ཉ', "ञ", "ñ"),
+	('ཊ', "ट", "ṭ"),
+	('ཋ', "ठ", "ṭh"),
+	('ཌ', "ड", "ḍ"),
+	('\u{0F4D}', "ढ", "ḍh"),
+	('ཎ', "ण", "ṇ"),
+	('ཏ', "त", "t"),
+	('ཐ', "थ", "th"),
+	('ད', "द", "d"),
+	('\u{0F52}', "ध", "dh"),
+	('ན', "न", "n"),
+	('པ', "प", "p"),
+	('ཕ', "फ", "ph"),
+	('བ', "ब", "b"),
+	('\u{0F57}', "भ", "bh"),
+	('མ', "म", "m"),
+	('ཙ', "च", "c"),
+	('ཚ', "छ", "ch"),
+	('ཛ', "ज", "j"),
+	('\u{0F5C}', "झ", "jh"),
+	('ཝ', "व", "v"),
+	('ཡ', "य", "y"),
+	('ར', "र", "r"),
+	('ལ', "ल", "l"),
+	('ཤ', "श", "ś"),
+	('ཥ', "ष", "ṣ"),
+	('ས', "स", "s"),
+	('ཧ', "ह", "h"),
+	('\u{0F69}', "क्ष", "kṣ"),
+	('ཪ', "र", "r"),
+];
+
+/// The letters that are written with a subjoined ཧ to spell the voiced aspirates of Sanskrit,
+/// which also have a precomposed code point of their own, e.g. གྷ for ག + ྷ.
+const LETTERS_ASPIRATED_BY_HA: [char; 5] = ['ག', 'ཌ', 'ད', 'བ', 'ཛ'];
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Vowel {
+	A,
+	I,
+	U,
+	VocalicR,
+	VocalicL,
+	E,
+	Ai,
+	O,
+	Au,
+}
+
+/// A vowel as an independent letter & as a sign in Devanagari, & in IAST.
+type VowelSpelling = (&'static str, &'static str, &'static str);
+
+/// The spellings of each vowel, short & then long.
+const SANSKRIT_VOWELS: [(Vowel, [VowelSpelling; 2]); 9] = [
+	(Vowel::A, [("अ", "", "a"), ("आ", "ा", "ā")]),
+	(Vowel::I, [("इ", "ि", "i"), ("ई", "ी", "ī")]),
+	(Vowel::U, [("उ", "ु", "u"), ("ऊ", "ू", "ū")]),
+	(Vowel::VocalicR, [("ऋ", "ृ", "ṛ"), ("ॠ", "ॄ", "ṝ")]),
+	(Vowel::VocalicL, [("ऌ", "ॢ", "ḷ"), ("ॡ", "ॣ", "ḹ")]),
+	(Vowel::E, [("ए", "े", "e"), ("ए", "े", "e")]),
+	(Vowel::Ai, [("ऐ", "ै", "ai"), ("ऐ", "ै", "ai")]),
+	(Vowel::O, [("ओ", "ो", "o"), ("ओ", "ो", "o")]),
+	(Vowel::Au, [("औ", "ौ", "au"), ("औ", "ौ", "au")]),
+];
+
+const VIRAMA: &str = "्";
+
+// A consonant with its subjoined consonants, vowel & marks, which is written as one akshara in Devanagari.
+#[derive(Debug)]
+struct Akshara {
+	consonants: Vec<char>,
+	vowel: Vowel,
+	long: bool,
+	halanta: bool,
+	marks: Vec<char>,
+}
+
+/// Transliterates Sanskrit written in Tibetan script, e.g. ཨོཾ་མ་ཎི་པདྨེ་ཧཱུྃ, into Devanagari or IAST.
+/// Syllables separated by a tsheg are separated by a space, as the words of a mantra usually are.
+/// Characters without a Sanskrit value are kept as they are.
+pub fn transliterate(text: &str, to: Transliteration) -> String {
+	let mut result = String::new();
+	let mut akshara: Option<Akshara> = None;
+	for c in text.chars() {
+		match (c, &mut akshara) {
+			// The vowel carrier ཨ starts an akshara without consonants.
+			('ཨ', _) => {
+				write_akshara(akshara.take(), to, &mut result);
+				akshara = Some(Akshara::new(vec![]));
+			}
+			('\u{0F40}'..='\u{0F6C}', _) => {
+				write_akshara(akshara.take(), to, &mut result);
+				akshara = Some(Akshara::new(vec![c]));
+			}
+			(_, Some(current)) if subjoined_letter(c).is_some() => {
+				let letter = subjoined_letter(c).unwrap();
+				match current.consonants.last() {
+					Some(&last) if letter == 'ཧ' && LETTERS_ASPIRATED_BY_HA.contains(&last) => {
+						current.consonants.pop();
+						current
+							.consonants
+							.push(char::from_u32(last as u32 + 1).unwrap());
+					}
+					Some('ཀ') if letter == 'ཥ' => {
+						current.consonants.pop();
+						current.consonants.push('\u{0F69}');
+					}
+					_ => current.consonants.push(letter),
+				}
+			}
+			(A_CHUNG_UNICODE_CODE_POINT, Some(current)) => current.long = true,
+			('\u{0F72}'..='\u{0F7D}' | '\u{0F80}' | '\u{0F81}', Some(current)) => {
+				current.add_vowel_sign(c)
+			}
+			('\u{0F7E}' | '\u{0F7F}' | '\u{0F82}' | '\u{0F83}', Some(current)) => {
+				current.marks.push(c)
+			}
+			('\u{0F84}', Some(current)) => current.halanta = true,
+			_ => {
+				write_akshara(akshara.take(), to, &mut result);
+				match c {
+					TSHEG_UNICODE_CODE_POINT | '\u{0F0C}' => result.push(' '),
+					SHAD_UNICODE_CODE_POINT | '\u{0F0E}' => result.push_str(match to {
+						Transliteration::Devanagari => "।",
+						Transliteration::Iast => "|",
+					}),
+					'\u{0F20}'..='\u{0F29}' => {
+						let digit = c as u32 - 0x0F20;
+						result.push(match to {
+							Transliteration::Devanagari => char::from_u32(0x0966 + digit).unwrap(),
+							Transliteration::Iast => char::from_digit(digit, 10).unwrap(),
+						});
+					}
+					_ => result.push(c),
+				}
+			}
+		}
+	}
+	write_akshara(akshara.take(), to, &mut result);
+
+	// A tsheg before a shad or a space does not separate anything.
+	let words: Vec<&str> = result.split(' ').filter(|w| !w.is_empty()).collect();
+	words.join(" ")
+}
+
+impl Akshara {
+	fn new(consonants: Vec<char>) -> Akshara {
+		Akshara {
+			consonants,
+			vowel: Vowel::A,
+			long: false,
+			halanta: false,
+			marks: vec![],
+		}
+	}
+
+	fn add_vowel_sign(&mut self, sign: char) {
+		self.vowel = match sign {
+			'\u{0F72}' => Vowel::I,
+			'\u{0F73}' => {
+				self.long = true;
+				Vowel::I
+			}
+			'\u{0F74}' => Vowel::U,
+			'\u{0F75}' => {
+				self.long = true;
+				Vowel::U
+			}
+			'\u{0F76}' | '\u{0F77}' => {
+				self.long = sign == '\u{0F77}';
+				Vowel::VocalicR
+			}
+			'\u{0F78}' | '\u{0F79}' => {
+				self.long = sign == '\u{0F79}';
+				Vowel::VocalicL
+			}
+			'\u{0F7A}' => Vowel::E,
+			'\u{0F7B}' => Vowel::Ai,
+			'\u{0F7C}' => Vowel::O,
+			'\u{0F7D}' => Vowel::Au,
+			// The reversed gigu spells the vocalic r & l, with a subjoined ར or ལ, e.g. ཀྲྀ "kṛ".
+			_ => {
+				if sign == '\u{0F81}' {
+					self.long = true;
+				}
+				match self.consonants.last() {
+					Some('ར') if self.consonants.len() > 1 => {
+						self.consonants.pop();
+						Vowel::VocalicR
+					}
+					Some('ལ') if self.consonants.len() > 1 => {
+						self.consonants.pop();
+						Vowel::VocalicL
+					}
+					_ => Vowel::I,
+				}
+			}
+		};
+	}
+}
+
+// Returns the letter of a subjoined consonant, e.g. ཀ for ྐ.
+fn subjoined_letter(c: char) -> Option<char> {
+	match c {
+		'\u{0FBA}' => Some('ཝ'),
+		'\u{0FBB}' => Some('ཡ'),
+		'\u{0FBC}' => Some('ར'),
+		'\u{0F90}'..='\u{0FB9}' => char::from_u32(c as u32 - 0x50),
+		_ => None,
+	}
+}
+
+fn write_akshara(akshara: Option<Akshara>, to: Transliteration, result: &mut String) {
+	let akshara = match akshara {
+		Some(akshara) => akshara,
+		None => return,
+	};
+	// A letter without a Sanskrit value is kept as it is.
+	let consonants: Vec<(String, String)> = akshara
+		.consonants
+		.iter()
+		.map(|c| {
+			match SANSKRIT_CONSONANTS
+				.iter()
+				.find(|(tibetan, _, _)| tibetan == c)
+			{
+				Some((_, devanagari, iast)) => (devanagari.to_string(), iast.to_string()),
+				None => (c.to_string(), c.to_string()),
+			}
+		})
+		.collect();
+	let vowel = SANSKRIT_VOWELS
+		.iter()
+		.find(|(v, _)| *v == akshara.vowel)
+		.unwrap()
+		.1[akshara.long as usize];
+
+	match to {
+		Transliteration::Devanagari => {
+			let letters: Vec<&str> = consonants
+				.iter()
+				.map(|(devanagari, _)| devanagari.as_str())
+				.collect();
+			result.push_str(&letters.join(VIRAMA));
+			if letters.is_empty() {
+				result.push_str(vowel.0);
+			} else if akshara.halanta {
+				result.push_str(VIRAMA);
+			} else {
+				result.push_str(vowel.1);
+			}
+			for mark in &akshara.marks {
+				result.push_str(match mark {
+					'\u{0F7E}' => "ं",
+					'\u{0F7F}' => "ः",
+					_ => "ँ",
+				});
+			}
+		}
+		Transliteration::Iast => {
+			for (_, iast) in &consonants {
+				result.push_str(iast);
+			}
+			if !akshara.halanta {
+				result.push_str(vowel.2);
+			}
+			for mark in &akshara.marks {
+				result.push_str(match mark {
+					'\u{0F7E}' => "ṃ",
+					'\u{0F7F}' => "ḥ",
+					_ => "m\u{0310}",
+				});
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn mantra() {
+		let mantra = "ཨོཾ་མ་ཎི་པདྨེ་ཧཱུྃ།";
+		assert_eq!(
+			"ओं म णि पद्मे हूँ।",
+			transliterate(mantra, Transliteration::Devanagari)
+		);
+		assert_eq!(
+			"oṃ ma ṇi padme hūm\u{0310}|",
+			transliterate(mantra, Transliteration::Iast)
+		);
+	}
+
+	#[test]
+	fn stacks() {
+		assert_eq!("vajra", transliterate("ཝཛྲ", Transliteration::Iast));
+		assert_eq!("वज्र", transliterate("ཝཛྲ", Transliteration::Devanagari));
+		assert_eq!("kṣa", transliterate("ཀྵ", Transliteration::Iast));
+		assert_eq!("gha", transliterate("\u{0F43}", Transliteration::Iast));
+		assert_eq!("gha", transliterate("ག\u{0FB7}", Transliteration::Iast));
+		assert_eq!("कृ", transliterate("ཀྲྀ", Transliteration::Devanagari));
+	}
+
+	#[test]
+	fn marks() {
+		assert_eq!("āḥ", transliterate("ཨཱཿ", Transliteration::Iast));
+		assert_eq!("आः", transliterate("ཨཱཿ", Transliteration::Devanagari));
+		assert_eq!("phaṭ", transliterate("ཕཊ྄", Transliteration::Iast));
+		assert_eq!("फट्", transliterate("ཕཊ྄", Transliteration::Devanagari));
+	}
+}
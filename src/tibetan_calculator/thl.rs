@@ -0,0 +1,105 @@
+use super::tibetan_data::*;
+use super::Pronunciation;
+
+/// The onsets that THL Simplified writes differently from the calculator's own scheme,
+/// with their spellings in a high & in a low tone.
+/// THL marks aspiration only for "kh", and writes the low-tone initials of the third column as voiced.
+const THL_ONSETS: [(&str, &str, &str); 8] = [
+	("kh", "kh", "g"),
+	("khy", "khy", "gy"),
+	("c", "ch", "ch"),
+	("ch", "ch", "j"),
+	("th", "t", "d"),
+	("thr", "tr", "dr"),
+	("ph", "p", "b"),
+	("tsh", "ts", "dz"),
+];
+
+// Writes the pronunciation of a syllable in THL Simplified Phonetic Transcription, e.g. "drup" for སྒྲུབ.
+pub(super) fn thl(pronunciation: &Pronunciation) -> String {
+	let low = matches!(
+		pronunciation.tone,
+		Tone::LOW | Tone::LOW_RISING | Tone::LOW_RISING_FALLING
+	);
+	let mut result = match THL_ONSETS
+		.iter()
+		.find(|(onset, _, _)| *onset == pronunciation.onset)
+	{
+		Some((_, high_onset, low_onset)) => {
+			String::from(if low { *low_onset } else { *high_onset })
+		}
+		None => pronunciation.onset.clone(),
+	};
+	// A changed "a" is written "e", while a changed "o" & "u" keep their diaeresis.
+	match (pronunciation.vowel, pronunciation.umlaut) {
+		('a', true) => result.push('e'),
+		(vowel, umlaut) => {
+			result.push(vowel);
+			if umlaut {
+				result.push(DIAIRESIS_UNICODE_CODE_POINT);
+			}
+		}
+	}
+	result.push_str(pronunciation.coda);
+	result
+}
+
+// THL writes an "e" ending a word as "é", e.g. "sangyé", even before a glottal stop.
+pub(super) fn mark_final_e(word: &mut String) {
+	let end = word.trim_end_matches(GLOTTAL_STOP_UNICODE_CODE_POINT).len();
+	if word[..end].ends_with('e') {
+		word.insert(end, HIGH_TONE_UNICODE_CODE_POINT);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::super::parser::*;
+	use super::super::*;
+
+	fn thl(text: &str) -> String {
+		let options = PhoneticOptions {
+			scheme: PhoneticScheme::ThlSimplified,
+			tone: ToneNotation::Accents,
+			..PhoneticOptions::default()
+		};
+		phonetic_word(&parse_tibetan_word(text).unwrap(), &options)
+	}
+
+	#[test]
+	fn syllables() {
+		assert_eq!("drup", thl("སྒྲུབ"));
+		assert_eq!("gyel", thl("རྒྱལ"));
+		assert_eq!("khyen", thl("མཁྱེན"));
+		assert_eq!("kün", thl("ཀུན"));
+		assert_eq!("ga", thl("ག"));
+		assert_eq!("tri", thl("ཁྲི"));
+	}
+
+	#[test]
+	fn words() {
+		assert_eq!("tupten", thl("ཐུབ་བསྟན"));
+		assert_eq!("sangyé", thl("སངས་རྒྱས"));
+		assert_eq!("rinpoché", thl("རིན་པོ་ཆེ"));
+		assert_eq!("kangyur", thl("བཀའ་འགྱུར"));
+		assert_eq!("wang", thl("དབང"));
+		assert_eq!("yang", thl("དབྱངས"));
+		assert_eq!("uma", thl("དབུ་མ"));
+	}
+
+	#[test]
+	fn glottal_stops() {
+		let options = PhoneticOptions {
+			scheme: PhoneticScheme::ThlSimplified,
+			tone: ToneNotation::Omitted,
+			glottal_stop: true,
+			..PhoneticOptions::default()
+		};
+		let syllable = parse_tibetan("སྐད").unwrap();
+		assert_eq!("ke\u{0301}\u{0294}", phonetic(&syllable, &options));
+		let word = parse_tibetan_word("སྐད").unwrap();
+		assert_eq!("ke\u{0301}\u{0294}", phonetic_word(&word, &options));
+		let word = parse_tibetan_word("སངས་རྒྱས").unwrap();
+		assert_eq!("sangye\u{0301}", phonetic_word(&word, &options));
+	}
+}
@@ -1,12 +1,82 @@
 /// A complete Tibetan syllable, which always has a central root character,
-/// and may optionally also have a prefix, superscript, subscript, & up to two suffixes.
+/// and may optionally also have a prefix, superscript, subscript, wa-zur, vowel sign, & up to two suffixes.
+/// A syllable without a vowel sign carries the inherent "a" of its root.
+/// An open syllable may instead end with a particle contracted onto it, such as the genitive འི.
+/// A syllable of Sanskrit may also lengthen its vowel with an a-chung, & carry a mark such as the anusvara.
+/// Its root stack may also have more letters than the superscript, root & subscript, such as ནྟ or སྒྲྭ,
+/// which are kept in `subjoined`, and follow other stacks with their own vowels, such as པ in པདྨ.
+#[derive(Debug, Clone)]
 pub struct TibetanSyllable {
 	pub root: &'static TibetanCharacter,
 	pub prefix: Option<&'static TibetanCharacter>,
 	pub superscript: Option<&'static TibetanCharacter>,
 	pub subscript: Option<&'static TibetanCharacter>,
+	/// Whether a wa is subjoined below the root & subscript, as in གྲྭ. It is silent in Lhasa Tibetan.
+	pub wa_zur: bool,
+	/// The letters subjoined below the root & subscript, from top to bottom, in stacks of Sanskrit.
+	pub subjoined: Vec<&'static TibetanCharacter>,
+	/// The stacks written before the root stack in a syllable of Sanskrit, such as པ in པདྨ.
+	pub preceding_stacks: Vec<Stack>,
+	pub vowel: Option<&'static TibetanVowel>,
 	pub suffix: Option<&'static TibetanCharacter>,
 	pub second_suffix: Option<&'static TibetanCharacter>,
+	pub particle: Option<&'static ContractedParticle>,
+	pub long_vowel: bool,
+	pub mark: Option<&'static SanskritMark>,
+}
+
+impl TibetanSyllable {
+	/// Returns the letters of the root stack from top to bottom: the superscript, the root, the subscript,
+	/// the wa-zur, then the other subjoined letters. The first letter is written on the line, & the others below it.
+	pub fn stack(&self) -> Vec<&'static TibetanCharacter> {
+		let mut result = vec![];
+		result.extend(self.superscript);
+		result.push(self.root);
+		result.extend(self.subscript);
+		if self.wa_zur {
+			result.push(ROOTS.iter().find(|r| r.tibetan == 'ཝ').unwrap());
+		}
+		result.extend(&self.subjoined);
+		result
+	}
+
+	/// Returns the root stack, with the vowel sign, a-chung & mark written on it.
+	pub fn root_stack(&self) -> Stack {
+		Stack {
+			letters: self.stack(),
+			vowel: self.vowel,
+			long_vowel: self.long_vowel,
+			mark: self.mark,
+		}
+	}
+
+	/// Returns whether the syllable is Sanskrit, either by its letters or by a long vowel or a mark of Sanskrit.
+	pub fn is_sanskrit(&self) -> bool {
+		self.has_sanskrit_letters() || self.long_vowel || self.mark.is_some()
+	}
+
+	/// Returns whether the letters of the syllable are spelled as Sanskrit, rather than by the rules of
+	/// Tibetan orthography: with several stacks, letters subjoined in a stack, or a letter of Sanskrit.
+	/// A long vowel or a mark alone may be added to a syllable otherwise spelled as Tibetan.
+	pub fn has_sanskrit_letters(&self) -> bool {
+		!self.subjoined.is_empty()
+			|| !self.preceding_stacks.is_empty()
+			|| SANSKRIT_ROOTS
+				.iter()
+				.any(|r| r.tibetan == self.root.tibetan)
+	}
+}
+
+/// A word made of one or more syllables, written separated by a tsheg.
+#[derive(Debug, Clone)]
+pub struct Word {
+	pub syllables: Vec<TibetanSyllable>,
+}
+
+/// A phrase made of one or more words.
+#[derive(Debug, Clone)]
+pub struct Phrase {
+	pub words: Vec<Word>,
 }
 
 /// A single Tibetan character, which may be a stand-alone syllable, or a part of one.
@@ -23,15 +93,21 @@ pub struct TibetanCharacter {
 }
 
 impl TibetanCharacter {
+	/// Returns the Wylie transliteration of the character without its inherent "a",
+	/// e.g. "k" for ཀ, or an empty string for ཨ.
+	pub fn wylie_consonant(&self) -> &'static str {
+		&self.wylie[..self.wylie.len() - 1]
+	}
+
 	/// Returns the available subscripts for the given Tibetan character.
 	pub fn available_subscripts(&self) -> Vec<char> {
 		match self.tibetan {
 			'ཀ' => vec!['ཡ', 'ར', 'ལ'],
 			'ཁ' => vec!['ཡ', 'ར'],
 			'ག' => vec!['ཡ', 'ར', 'ལ'],
-			'པ' => vec!['ཡ', 'ར', 'ལ'],
+			'པ' => vec!['ཡ', 'ར'],
 			'ཕ' => vec!['ཡ', 'ར'],
-			'བ' => vec!['ཡ', 'ར'],
+			'བ' => vec!['ཡ', 'ར', 'ལ'],
 			'མ' => vec!['ཡ', 'ར'],
 			'ཏ' => vec!['ར'],
 			'ཐ' => vec!['ར'],
@@ -41,9 +117,190 @@ impl TibetanCharacter {
 			'ས' => vec!['ར', 'ལ'],
 			'ཟ' => vec!['ལ'],
 			'ཧ' => vec!['ར'],
+			// Only found in words of Sanskrit, such as བཛྲ.
+			'ཛ' => vec!['ར'],
+			_ => vec![],
+		}
+	}
+
+	/// Returns whether a wa-zur may be subjoined to the given Tibetan character as a root,
+	/// below its subscript if it has one, as in ཀྭ, གྲྭ or དྲྭ.
+	/// ཕ only takes a wa-zur below the subscript ཡ, as in ཕྱྭ.
+	pub fn takes_wa_zur(&self, subscript: Option<&TibetanCharacter>) -> bool {
+		match (self.tibetan, subscript.map(|s| s.tibetan)) {
+			('ཕ', Some('ཡ')) => true,
+			(_, None) => WA_ZUR_ROOTS.contains(&self.tibetan),
+			('ག', Some('ར')) | ('ད', Some('ར')) => true,
+			_ => false,
+		}
+	}
+
+	/// Returns the prefixes that may precede the given Tibetan character as a root.
+	pub fn available_prefixes(&self) -> Vec<char> {
+		match self.tibetan {
+			'ཀ' => vec!['ད', 'བ'],
+			'ཁ' => vec!['མ', 'འ'],
+			'ག' => vec!['ད', 'བ', 'མ', 'འ'],
+			'ང' => vec!['ད', 'མ'],
+			'ཅ' => vec!['ག', 'བ'],
+			'ཆ' => vec!['མ', 'འ'],
+			'ཇ' => vec!['མ', 'འ'],
+			'ཉ' => vec!['ག', 'མ'],
+			'ཏ' => vec!['ག', 'བ'],
+			'ཐ' => vec!['མ', 'འ'],
+			'ད' => vec!['ག', 'བ', 'མ', 'འ'],
+			'ན' => vec!['ག', 'མ'],
+			'པ' => vec!['ད'],
+			'ཕ' => vec!['འ'],
+			'བ' => vec!['ད', 'འ'],
+			'མ' => vec!['ད'],
+			'ཙ' => vec!['ག', 'བ'],
+			'ཚ' => vec!['མ', 'འ'],
+			'ཛ' => vec!['མ', 'འ'],
+			'ཞ' => vec!['ག', 'བ'],
+			'ཟ' => vec!['ག', 'བ'],
+			'ཡ' => vec!['ག'],
+			'ཤ' => vec!['ག', 'བ'],
+			'ས' => vec!['ག', 'བ'],
+			_ => vec![],
+		}
+	}
+
+	/// Returns the superscripts that may be placed above the given Tibetan character as a root.
+	pub fn available_superscripts(&self) -> Vec<char> {
+		match self.tibetan {
+			'ཀ' => vec!['ར', 'ལ', 'ས'],
+			'ག' => vec!['ར', 'ལ', 'ས'],
+			'ང' => vec!['ར', 'ལ', 'ས'],
+			'ཅ' => vec!['ལ'],
+			'ཇ' => vec!['ར', 'ལ'],
+			'ཉ' => vec!['ར', 'ས'],
+			'ཏ' => vec!['ར', 'ལ', 'ས'],
+			'ད' => vec!['ར', 'ལ', 'ས'],
+			'ན' => vec!['ར', 'ས'],
+			'པ' => vec!['ལ', 'ས'],
+			'བ' => vec!['ར', 'ལ', 'ས'],
+			'མ' => vec!['ར', 'ས'],
+			'ཙ' => vec!['ར', 'ས'],
+			'ཛ' => vec!['ར'],
+			'ཧ' => vec!['ལ'],
 			_ => vec![],
 		}
 	}
+
+	/// Returns the second suffixes that may follow the given Tibetan character as a suffix.
+	pub fn available_second_suffixes(&self) -> Vec<char> {
+		match self.tibetan {
+			'ག' | 'ང' | 'བ' | 'མ' => vec!['ས'],
+			'ན' | 'ར' | 'ལ' => vec!['ད'],
+			_ => vec![],
+		}
+	}
+}
+
+/// A Tibetan vowel sign, written above or below the root of a syllable.
+#[derive(Debug)]
+pub struct TibetanVowel {
+	pub tibetan: char,
+	pub wylie: &'static str,
+	pub phonetic: &'static str,
+	pub unicode_code_point: char,
+}
+
+/// A particle contracted onto an open syllable, written with an a-chung, such as པོའི.
+/// The a-chung takes the place of the suffix of the syllable.
+#[derive(Debug)]
+pub struct ContractedParticle {
+	pub tibetan: &'static str,
+	pub wylie: &'static str,
+	pub phonetic: &'static str,
+	/// Whether the particle changes the vowel of the syllable, like one of `SUFFIXES_THAT_CAUSE_VOWEL_CHANGE`.
+	pub causes_vowel_change: bool,
+	/// Whether the particle closes the syllable with a consonant.
+	pub closes_syllable: bool,
+}
+
+/// A letter written on the line, along with any letters subjoined to it and the vowel sign written on it,
+/// such as པ in པདྨ, or the root stack of a syllable.
+/// A stack of Sanskrit may also lengthen its vowel with an a-chung, & carry a mark.
+#[derive(Debug, Clone)]
+pub struct Stack {
+	/// The letters from top to bottom, the first written on the line, & the others below it.
+	pub letters: Vec<&'static TibetanCharacter>,
+	pub vowel: Option<&'static TibetanVowel>,
+	pub long_vowel: bool,
+	pub mark: Option<&'static SanskritMark>,
+}
+
+impl Stack {
+	pub fn new(
+		letters: Vec<&'static TibetanCharacter>,
+		vowel: Option<&'static TibetanVowel>,
+	) -> Stack {
+		Stack {
+			letters,
+			vowel,
+			long_vowel: false,
+			mark: None,
+		}
+	}
+
+	/// Returns whether the stack has more than one letter or carries a vowel sign, an a-chung or a mark,
+	/// so that it can be neither a prefix nor a suffix.
+	pub fn is_marked(&self) -> bool {
+		self.letters.len() > 1 || self.vowel.is_some() || self.long_vowel || self.mark.is_some()
+	}
+
+	/// Returns the stack as a String.
+	pub fn tibetan(&self) -> String {
+		let mut result = String::new();
+		for (i, letter) in self.letters.iter().enumerate() {
+			if i == 0 {
+				result.push(letter.unicode_code_point);
+			} else {
+				result.push(letter.unicode_code_point_as_subscript);
+			}
+		}
+		if self.long_vowel {
+			result.push(A_CHUNG_UNICODE_CODE_POINT);
+		}
+		if let Some(vowel) = self.vowel {
+			result.push(vowel.unicode_code_point);
+		}
+		if let Some(mark) = self.mark {
+			result.push(mark.unicode_code_point);
+		}
+		result
+	}
+}
+
+/// A mark written above or after a syllable of Sanskrit, such as the anusvara in ཨོཾ.
+#[derive(Debug)]
+pub struct SanskritMark {
+	pub tibetan: char,
+	pub wylie: &'static str,
+	/// The customary Tibetan reading of the mark, which closes the syllable.
+	pub phonetic: &'static str,
+	pub unicode_code_point: char,
+}
+
+/// The customary Tibetan reading of a syllable of Sanskrit that does not follow from its spelling,
+/// given as the onset, vowel & coda of each syllable that is pronounced, e.g. "benza" for བཛྲ.
+#[derive(Debug)]
+pub struct CustomaryReading {
+	pub tibetan: &'static str,
+	pub syllables: &'static [(&'static str, char, &'static str)],
+}
+
+/// A stack whose reading does not follow from the reading of its root, such as ལྷ, read as a voiceless "lh".
+#[derive(Debug)]
+pub struct Ligature {
+	pub superscript: Option<char>,
+	pub root: char,
+	pub subscript: Option<char>,
+	pub phonetic: &'static str,
+	/// The register of the stack, whether or not it follows a prefix.
+	pub tone: Tone,
 }
 
 /// What column a character is found in, in the Tibetan alphabet table.
@@ -57,17 +314,80 @@ pub enum Column {
 }
 
 /// A syllable/character's tone.
+/// HIGH & LOW are the registers of a syllable, determined by its initial.
+/// The four contours of Lhasa Tibetan further depend on how the syllable ends.
+#[allow(non_camel_case_types)]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Tone {
 	HIGH,
 	LOW,
-	MID,
-	NONE,
+	HIGH_LEVEL,
+	HIGH_FALLING,
+	LOW_RISING,
+	LOW_RISING_FALLING,
+}
+
+impl Tone {
+	/// Returns the combining accent written over the vowel for this tone.
+	pub fn accent(&self) -> char {
+		match self {
+			Tone::HIGH | Tone::HIGH_LEVEL => HIGH_TONE_UNICODE_CODE_POINT,
+			Tone::HIGH_FALLING => HIGH_FALLING_TONE_UNICODE_CODE_POINT,
+			Tone::LOW | Tone::LOW_RISING => LOW_TONE_UNICODE_CODE_POINT,
+			Tone::LOW_RISING_FALLING => LOW_RISING_FALLING_TONE_UNICODE_CODE_POINT,
+		}
+	}
+
+	/// Returns the superscript number written after a syllable for this tone, from ¹ to ⁴.
+	pub fn number(&self) -> char {
+		match self {
+			Tone::HIGH | Tone::HIGH_LEVEL => '¹',
+			Tone::HIGH_FALLING => '²',
+			Tone::LOW | Tone::LOW_RISING => '³',
+			Tone::LOW_RISING_FALLING => '⁴',
+		}
+	}
+
+	/// Returns the IPA (Chao) tone letters for this tone.
+	pub fn tone_letters(&self) -> &'static str {
+		match self {
+			Tone::HIGH | Tone::HIGH_LEVEL => "˥˥",
+			Tone::HIGH_FALLING => "˥˧",
+			Tone::LOW | Tone::LOW_RISING => "˩˧",
+			Tone::LOW_RISING_FALLING => "˩˧˨",
+		}
+	}
+}
+
+impl std::fmt::Display for Tone {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Tone::HIGH => write!(f, "high"),
+			Tone::LOW => write!(f, "low"),
+			Tone::HIGH_LEVEL => write!(f, "high level"),
+			Tone::HIGH_FALLING => write!(f, "high falling"),
+			Tone::LOW_RISING => write!(f, "low rising"),
+			Tone::LOW_RISING_FALLING => write!(f, "low rising-falling"),
+		}
+	}
 }
 
+pub const TSHEG_UNICODE_CODE_POINT: char = '\u{0F0B}';
+pub const SHAD_UNICODE_CODE_POINT: char = '\u{0F0D}';
+pub const A_CHUNG_UNICODE_CODE_POINT: char = '\u{0F71}';
 pub const DIAIRESIS_UNICODE_CODE_POINT: char = '\u{0308}';
+pub const GLOTTAL_STOP_UNICODE_CODE_POINT: char = '\u{0294}';
+pub const MACRON_UNICODE_CODE_POINT: char = '\u{0304}';
 pub const HIGH_TONE_UNICODE_CODE_POINT: char = '\u{0301}';
+pub const HIGH_FALLING_TONE_UNICODE_CODE_POINT: char = '\u{0302}';
 pub const LOW_TONE_UNICODE_CODE_POINT: char = '\u{0300}';
+pub const LOW_RISING_FALLING_TONE_UNICODE_CODE_POINT: char = '\u{030C}';
+pub const TONE_UNICODE_CODE_POINTS: [char; 4] = [
+	HIGH_TONE_UNICODE_CODE_POINT,
+	HIGH_FALLING_TONE_UNICODE_CODE_POINT,
+	LOW_TONE_UNICODE_CODE_POINT,
+	LOW_RISING_FALLING_TONE_UNICODE_CODE_POINT,
+];
 
 /// All 30 Tibetan consonants.
 pub const ROOTS: [TibetanCharacter; 30] = [
@@ -373,12 +693,326 @@ pub const ROOTS: [TibetanCharacter; 30] = [
 	},
 ];
 
+/// The letters used to write Sanskrit, besides the 30 Tibetan consonants:
+/// the retroflex letters, ཥ, ཀྵ, & the voiced aspirates, which are written with a subjoined ཧ.
+/// Their phonetic values are their customary Tibetan readings.
+/// The letters with a canonical decomposition, such as གྷ, are written as escapes.
+pub const SANSKRIT_ROOTS: [TibetanCharacter; 11] = [
+	TibetanCharacter {
+		tibetan: 'ཊ',
+		wylie: "Ta",
+		phonetic: "ta",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "",
+		unicode_code_point: '\u{0F4A}',
+		unicode_code_point_as_subscript: '\u{0F9A}',
+		column: Column::FIRST,
+	},
+	TibetanCharacter {
+		tibetan: 'ཋ',
+		wylie: "Tha",
+		phonetic: "tha",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "",
+		unicode_code_point: '\u{0F4B}',
+		unicode_code_point_as_subscript: '\u{0F9B}',
+		column: Column::SECOND,
+	},
+	TibetanCharacter {
+		tibetan: 'ཌ',
+		wylie: "Da",
+		phonetic: "tha",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "da",
+		unicode_code_point: '\u{0F4C}',
+		unicode_code_point_as_subscript: '\u{0F9C}',
+		column: Column::THIRD,
+	},
+	TibetanCharacter {
+		tibetan: 'ཎ',
+		wylie: "Na",
+		phonetic: "na",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "",
+		unicode_code_point: '\u{0F4E}',
+		unicode_code_point_as_subscript: '\u{0F9E}',
+		column: Column::FOURTH,
+	},
+	TibetanCharacter {
+		tibetan: 'ཥ',
+		wylie: "Sha",
+		phonetic: "sha",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "",
+		unicode_code_point: '\u{0F65}',
+		unicode_code_point_as_subscript: '\u{0FB5}',
+		column: Column::NONE,
+	},
+	TibetanCharacter {
+		tibetan: '\u{0F69}',
+		wylie: "kSha",
+		phonetic: "ksha",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "",
+		unicode_code_point: '\u{0F69}',
+		unicode_code_point_as_subscript: '\u{0FB9}',
+		column: Column::FIRST,
+	},
+	TibetanCharacter {
+		tibetan: '\u{0F43}',
+		wylie: "gha",
+		phonetic: "kha",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "ga",
+		unicode_code_point: '\u{0F43}',
+		unicode_code_point_as_subscript: '\u{0F93}',
+		column: Column::THIRD,
+	},
+	TibetanCharacter {
+		tibetan: '\u{0F4D}',
+		wylie: "Dha",
+		phonetic: "tha",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "da",
+		unicode_code_point: '\u{0F4D}',
+		unicode_code_point_as_subscript: '\u{0F9D}',
+		column: Column::THIRD,
+	},
+	TibetanCharacter {
+		tibetan: '\u{0F52}',
+		wylie: "dha",
+		phonetic: "tha",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "da",
+		unicode_code_point: '\u{0F52}',
+		unicode_code_point_as_subscript: '\u{0FA2}',
+		column: Column::THIRD,
+	},
+	TibetanCharacter {
+		tibetan: '\u{0F57}',
+		wylie: "bha",
+		phonetic: "pha",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "ba",
+		unicode_code_point: '\u{0F57}',
+		unicode_code_point_as_subscript: '\u{0FA7}',
+		column: Column::THIRD,
+	},
+	TibetanCharacter {
+		tibetan: '\u{0F5C}',
+		wylie: "dzha",
+		phonetic: "dza",
+		phonetic_as_suffix: "",
+		phonetic_modified_third_column: "dza",
+		unicode_code_point: '\u{0F5C}',
+		unicode_code_point_as_subscript: '\u{0FAC}',
+		column: Column::THIRD,
+	},
+];
+
+/// The letters of `SANSKRIT_ROOTS` that are equivalent to a letter with a subjoined ཧ or ཥ,
+/// as the root, the subjoined letter & the letter itself.
+pub const SANSKRIT_DECOMPOSITIONS: [(char, char, char); 6] = [
+	('ཀ', 'ཥ', '\u{0F69}'),
+	('ག', 'ཧ', '\u{0F43}'),
+	('ཌ', 'ཧ', '\u{0F4D}'),
+	('ད', 'ཧ', '\u{0F52}'),
+	('བ', 'ཧ', '\u{0F57}'),
+	('ཛ', 'ཧ', '\u{0F5C}'),
+];
+
+/// Returns the 30 Tibetan consonants, followed by the letters used to write Sanskrit.
+pub fn all_letters() -> impl Iterator<Item = &'static TibetanCharacter> {
+	ROOTS.iter().chain(SANSKRIT_ROOTS.iter())
+}
+
+/// The four vowel signs: gigu, zhabkyu, drengbu & naro.
+pub const VOWELS: [TibetanVowel; 4] = [
+	TibetanVowel {
+		tibetan: 'ི',
+		wylie: "i",
+		phonetic: "i",
+		unicode_code_point: '\u{0F72}',
+	},
+	TibetanVowel {
+		tibetan: 'ུ',
+		wylie: "u",
+		phonetic: "u",
+		unicode_code_point: '\u{0F74}',
+	},
+	TibetanVowel {
+		tibetan: 'ེ',
+		wylie: "e",
+		phonetic: "e",
+		unicode_code_point: '\u{0F7A}',
+	},
+	TibetanVowel {
+		tibetan: 'ོ',
+		wylie: "o",
+		phonetic: "o",
+		unicode_code_point: '\u{0F7C}',
+	},
+];
+
+/// The vowels that take a diairesis when followed by one of `SUFFIXES_THAT_CAUSE_VOWEL_CHANGE`,
+/// besides the inherent "a" (ä, ü, ö). The vowels "i" & "e" are left unchanged.
+pub const VOWELS_THAT_TAKE_DIAIRESIS: [char; 2] = ['ུ', 'ོ'];
+
+/// The particles that may be contracted onto an open syllable:
+/// the genitive 'i, the final 'o, the alternative 'am & the concessive 'ang.
+pub const CONTRACTED_PARTICLES: [ContractedParticle; 4] = [
+	ContractedParticle {
+		tibetan: "འི",
+		wylie: "'i",
+		phonetic: "",
+		causes_vowel_change: true,
+		closes_syllable: false,
+	},
+	ContractedParticle {
+		tibetan: "འོ",
+		wylie: "'o",
+		phonetic: "o",
+		causes_vowel_change: false,
+		closes_syllable: false,
+	},
+	ContractedParticle {
+		tibetan: "འམ",
+		wylie: "'am",
+		phonetic: "m",
+		causes_vowel_change: false,
+		closes_syllable: true,
+	},
+	ContractedParticle {
+		tibetan: "འང",
+		wylie: "'ang",
+		phonetic: "ng",
+		causes_vowel_change: false,
+		closes_syllable: true,
+	},
+];
+
+/// The marks of Sanskrit: the anusvara, the candrabindu & the visarga.
+pub const SANSKRIT_MARKS: [SanskritMark; 3] = [
+	SanskritMark {
+		tibetan: 'ཾ',
+		wylie: "M",
+		phonetic: "m",
+		unicode_code_point: '\u{0F7E}',
+	},
+	SanskritMark {
+		tibetan: 'ྃ',
+		wylie: "~M",
+		phonetic: "ng",
+		unicode_code_point: '\u{0F83}',
+	},
+	SanskritMark {
+		tibetan: 'ཿ',
+		wylie: "H",
+		phonetic: "h",
+		unicode_code_point: '\u{0F7F}',
+	},
+];
+
+/// The syllables of Sanskrit whose customary Tibetan reading is irregular.
+pub const CUSTOMARY_READINGS: [CustomaryReading; 3] = [
+	CustomaryReading {
+		tibetan: "བཛྲ",
+		syllables: &[("b", 'e', "n"), ("z", 'a', "")],
+	},
+	CustomaryReading {
+		tibetan: "པདྨ",
+		syllables: &[("p", 'e', ""), ("m", 'a', "")],
+	},
+	CustomaryReading {
+		tibetan: "པདྨེ",
+		syllables: &[("p", 'e', ""), ("m", 'e', "")],
+	},
+];
+
+/// The stacks with an irregular reading: the la superscript over ha,
+/// & the la subscript, which silences the root above it, except for ཟ.
+pub const LIGATURES: [Ligature; 7] = [
+	Ligature {
+		superscript: Some('ལ'),
+		root: 'ཧ',
+		subscript: None,
+		phonetic: "lha",
+		tone: Tone::HIGH,
+	},
+	Ligature {
+		superscript: None,
+		root: 'ཟ',
+		subscript: Some('ལ'),
+		phonetic: "da",
+		tone: Tone::LOW,
+	},
+	Ligature {
+		superscript: None,
+		root: 'ཀ',
+		subscript: Some('ལ'),
+		phonetic: "la",
+		tone: Tone::HIGH,
+	},
+	Ligature {
+		superscript: None,
+		root: 'ག',
+		subscript: Some('ལ'),
+		phonetic: "la",
+		tone: Tone::HIGH,
+	},
+	Ligature {
+		superscript: None,
+		root: 'བ',
+		subscript: Some('ལ'),
+		phonetic: "la",
+		tone: Tone::HIGH,
+	},
+	Ligature {
+		superscript: None,
+		root: 'ར',
+		subscript: Some('ལ'),
+		phonetic: "la",
+		tone: Tone::HIGH,
+	},
+	Ligature {
+		superscript: None,
+		root: 'ས',
+		subscript: Some('ལ'),
+		phonetic: "la",
+		tone: Tone::HIGH,
+	},
+];
+
+/// The characters outside the four columns that have a high tone.
+pub const HIGH_TONE_CHARACTERS: [char; 5] = ['ཤ', 'ས', 'ཧ', 'ཨ', 'ཥ'];
+
+/// The characters outside the four columns that, like the fourth column,
+/// take a high tone when preceded by a prefix or a superscript.
+pub const CHARACTERS_RAISED_BY_PREFIX: [char; 4] = ['ཡ', 'ར', 'ལ', 'ཝ'];
+
+/// The suffixes that close a syllable with a glottal stop, giving it a falling contour.
+pub const SUFFIXES_THAT_CAUSE_FALLING_TONE: [char; 3] = ['ག', 'བ', 'ད'];
+
+/// The suffixes that are glottalised by the second suffix ས, as in སངས.
+pub const SUFFIXES_GLOTTALISED_BY_SECOND_SUFFIX: [char; 4] = ['ག', 'ང', 'བ', 'མ'];
+
+/// The roots that may take a wa-zur, as in ཀྭ.
+pub const WA_ZUR_ROOTS: [char; 16] = [
+	'ཀ', 'ཁ', 'ག', 'ཅ', 'ཉ', 'ཏ', 'ད', 'ཙ', 'ཚ', 'ཞ', 'ཟ', 'ར', 'ལ', 'ཤ', 'ས', 'ཧ',
+];
+
 pub const SUPERSCRIPTS: [char; 3] = ['ར', 'ལ', 'ས'];
 
+/// The only prefix that may precede a root with a superscript.
+pub const PREFIX_BEFORE_SUPERSCRIPT: char = 'བ';
+
 pub const PREFIXES: [char; 5] = ['ག', 'ད', 'བ', 'མ', 'འ'];
 
 pub const SUFFIXES: [char; 10] = ['ག', 'ང', 'ད', 'ན', 'བ', 'མ', 'འ', 'ར', 'ལ', 'ས'];
 
 pub const SUFFIXES_THAT_CAUSE_VOWEL_CHANGE: [char; 4] = ['ད', 'ན', 'ལ', 'ས'];
 
+/// The suffixes that lengthen the vowel of a syllable without a glottal stop, as in དཀའ or ལས.
+pub const SUFFIXES_THAT_LENGTHEN_VOWEL: [char; 3] = ['འ', 'ལ', 'ས'];
+
 pub const SECOND_SUFFIXES: [char; 2] = ['ད', 'ས'];
@@ -0,0 +1,414 @@
+use std::fmt;
+
+use super::tibetan;
+use super::tibetan_data::*;
+
+/// A position around the root of a syllable that may hold a letter.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Slot {
+	Prefix,
+	Superscript,
+	Subscript,
+	WaZur,
+	Suffix,
+	SecondSuffix,
+}
+
+impl fmt::Display for Slot {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Slot::Prefix => write!(f, "prefix"),
+			Slot::Superscript => write!(f, "superscript"),
+			Slot::Subscript => write!(f, "subscript"),
+			Slot::WaZur => write!(f, "wa-zur"),
+			Slot::Suffix => write!(f, "suffix"),
+			Slot::SecondSuffix => write!(f, "second suffix"),
+		}
+	}
+}
+
+/// A combination of letters that Tibetan orthography does not allow.
+#[derive(Debug, PartialEq)]
+pub enum SpellingError {
+	PrefixNotAllowed { prefix: char, root: char },
+	PrefixBeforeSuperscript { prefix: char, superscript: char },
+	SuperscriptNotAllowed { superscript: char, root: char },
+	SubscriptNotAllowed { root: char, subscript: char },
+	WaZurNotAllowed(String),
+	SuffixNotAllowed(char),
+	SecondSuffixNotAllowed { suffix: char, second_suffix: char },
+	SecondSuffixWithoutSuffix(char),
+	ParticleAfterSuffix(char),
+}
+
+impl SpellingError {
+	/// Returns the slot holding the letter that is not allowed, which should be removed to fix the error.
+	pub fn slot(&self) -> Slot {
+		match self {
+			SpellingError::PrefixNotAllowed { .. }
+			| SpellingError::PrefixBeforeSuperscript { .. } => Slot::Prefix,
+			SpellingError::SuperscriptNotAllowed { .. } => Slot::Superscript,
+			SpellingError::SubscriptNotAllowed { .. } => Slot::Subscript,
+			SpellingError::WaZurNotAllowed(_) => Slot::WaZur,
+			SpellingError::SuffixNotAllowed(_) | SpellingError::ParticleAfterSuffix(_) => {
+				Slot::Suffix
+			}
+			SpellingError::SecondSuffixNotAllowed { .. }
+			| SpellingError::SecondSuffixWithoutSuffix(_) => Slot::SecondSuffix,
+		}
+	}
+}
+
+impl fmt::Display for SpellingError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			SpellingError::PrefixNotAllowed { prefix, root } => {
+				write!(f, "{} cannot be a prefix to {}.", prefix, root)
+			}
+			SpellingError::PrefixBeforeSuperscript {
+				prefix,
+				superscript,
+			} => write!(
+				f,
+				"{} cannot be a prefix before the superscript {}, only {} can.",
+				prefix, superscript, PREFIX_BEFORE_SUPERSCRIPT
+			),
+			SpellingError::SuperscriptNotAllowed { superscript, root } => {
+				write!(f, "{} cannot be a superscript on {}.", superscript, root)
+			}
+			SpellingError::SubscriptNotAllowed { root, subscript } => {
+				write!(f, "{} cannot be a subscript under {}.", subscript, root)
+			}
+			SpellingError::WaZurNotAllowed(stack) => write!(f, "{} cannot take a wa-zur.", stack),
+			SpellingError::SuffixNotAllowed(suffix) => write!(f, "{} cannot be a suffix.", suffix),
+			SpellingError::SecondSuffixNotAllowed {
+				suffix,
+				second_suffix,
+			} => write!(
+				f,
+				"{} cannot be a second suffix after {}.",
+				second_suffix, suffix
+			),
+			SpellingError::SecondSuffixWithoutSuffix(second_suffix) => {
+				write!(
+					f,
+					"The second suffix {} requires a first suffix.",
+					second_suffix
+				)
+			}
+			SpellingError::ParticleAfterSuffix(suffix) => write!(
+				f,
+				"{} cannot be a suffix before a contracted particle, which may only follow an open syllable.",
+				suffix
+			),
+		}
+	}
+}
+
+/// Checks that every letter of the syllable may be combined with the others,
+/// returning all the spelling errors found.
+pub fn validate(syllable: &TibetanSyllable) -> Result<(), Vec<SpellingError>> {
+	// The syllables of Sanskrit, such as པདྨ or བཛྲ, are not spelled by the rules of Tibetan orthography.
+	if syllable.has_sanskrit_letters()
+		|| CUSTOMARY_READINGS
+			.iter()
+			.any(|r| r.tibetan == tibetan(syllable))
+	{
+		return Ok(());
+	}
+
+	let mut errors = vec![];
+	let root = syllable.root.tibetan;
+
+	if let Some(prefix) = syllable.prefix {
+		match syllable.superscript {
+			Some(superscript) if prefix.tibetan != PREFIX_BEFORE_SUPERSCRIPT => {
+				errors.push(SpellingError::PrefixBeforeSuperscript {
+					prefix: prefix.tibetan,
+					superscript: superscript.tibetan,
+				});
+			}
+			Some(_) => (),
+			None if !syllable.root.available_prefixes().contains(&prefix.tibetan) => {
+				errors.push(SpellingError::PrefixNotAllowed {
+					prefix: prefix.tibetan,
+					root,
+				});
+			}
+			None => (),
+		}
+	}
+
+	if let Some(superscript) = syllable.superscript {
+		if !syllable
+			.root
+			.available_superscripts()
+			.contains(&superscript.tibetan)
+		{
+			errors.push(SpellingError::SuperscriptNotAllowed {
+				superscript: superscript.tibetan,
+				root,
+			});
+		}
+	}
+
+	if let Some(subscript) = syllable.subscript {
+		if !syllable
+			.root
+			.available_subscripts()
+			.contains(&subscript.tibetan)
+		{
+			errors.push(SpellingError::SubscriptNotAllowed {
+				root,
+				subscript: subscript.tibetan,
+			});
+		}
+	}
+
+	if syllable.wa_zur && !syllable.root.takes_wa_zur(syllable.subscript) {
+		let mut stack = String::from(syllable.root.tibetan);
+		stack.extend(
+			syllable
+				.subscript
+				.map(|s| s.unicode_code_point_as_subscript),
+		);
+		errors.push(SpellingError::WaZurNotAllowed(stack));
+	}
+
+	if let Some(suffix) = syllable.suffix {
+		if !SUFFIXES.contains(&suffix.tibetan) {
+			errors.push(SpellingError::SuffixNotAllowed(suffix.tibetan));
+		}
+	}
+
+	if let Some(second_suffix) = syllable.second_suffix {
+		match syllable.suffix {
+			Some(suffix)
+				if !suffix
+					.available_second_suffixes()
+					.contains(&second_suffix.tibetan) =>
+			{
+				errors.push(SpellingError::SecondSuffixNotAllowed {
+					suffix: suffix.tibetan,
+					second_suffix: second_suffix.tibetan,
+				});
+			}
+			Some(_) => (),
+			None => errors.push(SpellingError::SecondSuffixWithoutSuffix(
+				second_suffix.tibetan,
+			)),
+		}
+	}
+
+	// The a-chung of a contracted particle takes the place of the suffix.
+	if syllable.particle.is_some() {
+		if let Some(suffix) = syllable.suffix {
+			errors.push(SpellingError::ParticleAfterSuffix(suffix.tibetan));
+		}
+	}
+
+	if errors.is_empty() {
+		Ok(())
+	} else {
+		Err(errors)
+	}
+}
+
+/// Returns the letters that may fill the given slot of the syllable,
+/// without introducing a spelling error with the letters in its other slots.
+pub fn available_letters(syllable: &TibetanSyllable, slot: Slot) -> Vec<char> {
+	let candidates: Vec<char> = match slot {
+		Slot::Prefix => PREFIXES.to_vec(),
+		Slot::Superscript => SUPERSCRIPTS.to_vec(),
+		Slot::Subscript => syllable.root.available_subscripts(),
+		Slot::WaZur => vec!['ཝ'],
+		Slot::Suffix => SUFFIXES.to_vec(),
+		Slot::SecondSuffix => SECOND_SUFFIXES.to_vec(),
+	};
+	let errors_without_letter = error_list(&with_letter(syllable, slot, None));
+
+	candidates
+		.into_iter()
+		.filter(|&c| {
+			let letter = ROOTS.iter().find(|&t| t.tibetan == c);
+			error_list(&with_letter(syllable, slot, letter))
+				.iter()
+				.all(|e| errors_without_letter.contains(e))
+		})
+		.collect()
+}
+
+// Returns a copy of the syllable, with the given slot replaced by the given letter.
+fn with_letter(
+	syllable: &TibetanSyllable,
+	slot: Slot,
+	letter: Option<&'static TibetanCharacter>,
+) -> TibetanSyllable {
+	let mut result = syllable.clone();
+	match slot {
+		Slot::Prefix => result.prefix = letter,
+		Slot::Superscript => result.superscript = letter,
+		Slot::Subscript => result.subscript = letter,
+		Slot::WaZur => result.wa_zur = letter.is_some(),
+		Slot::Suffix => result.suffix = letter,
+		Slot::SecondSuffix => result.second_suffix = letter,
+	}
+	result
+}
+
+fn error_list(syllable: &TibetanSyllable) -> Vec<SpellingError> {
+	validate(syllable).err().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn valid_syllable() {
+		let syllable = TibetanSyllable {
+			root: ROOTS.iter().find(|&t| t.tibetan == 'ག').unwrap(),
+			prefix: ROOTS.iter().find(|&t| t.tibetan == 'བ'),
+			superscript: ROOTS.iter().find(|&t| t.tibetan == 'ས'),
+			subscript: ROOTS.iter().find(|&t| t.tibetan == 'ར'),
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: VOWELS.iter().find(|&v| v.tibetan == 'ུ'),
+			suffix: ROOTS.iter().find(|&t| t.tibetan == 'བ'),
+			second_suffix: ROOTS.iter().find(|&t| t.tibetan == 'ས'),
+			particle: None,
+			long_vowel: false,
+			mark: None,
+		};
+
+		assert_eq!(Ok(()), validate(&syllable));
+	}
+
+	#[test]
+	fn invalid_syllable() {
+		let syllable = TibetanSyllable {
+			root: ROOTS.iter().find(|&t| t.tibetan == 'ཁ').unwrap(),
+			prefix: ROOTS.iter().find(|&t| t.tibetan == 'ག'),
+			superscript: ROOTS.iter().find(|&t| t.tibetan == 'ར'),
+			subscript: None,
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: None,
+			suffix: ROOTS.iter().find(|&t| t.tibetan == 'ན'),
+			second_suffix: ROOTS.iter().find(|&t| t.tibetan == 'ས'),
+			particle: None,
+			long_vowel: false,
+			mark: None,
+		};
+
+		assert_eq!(
+			Err(vec![
+				SpellingError::PrefixBeforeSuperscript {
+					prefix: 'ག',
+					superscript: 'ར'
+				},
+				SpellingError::SuperscriptNotAllowed {
+					superscript: 'ར',
+					root: 'ཁ'
+				},
+				SpellingError::SecondSuffixNotAllowed {
+					suffix: 'ན',
+					second_suffix: 'ས'
+				},
+			]),
+			validate(&syllable)
+		);
+	}
+
+	#[test]
+	fn prefix_not_allowed() {
+		let syllable = TibetanSyllable {
+			root: ROOTS.iter().find(|&t| t.tibetan == 'པ').unwrap(),
+			prefix: ROOTS.iter().find(|&t| t.tibetan == 'ག'),
+			superscript: None,
+			subscript: None,
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: None,
+			suffix: None,
+			second_suffix: None,
+			particle: None,
+			long_vowel: false,
+			mark: None,
+		};
+
+		assert_eq!(
+			Err(vec![SpellingError::PrefixNotAllowed {
+				prefix: 'ག',
+				root: 'པ'
+			}]),
+			validate(&syllable)
+		);
+
+		// A long vowel or a mark of Sanskrit does not exempt the letters from the rules.
+		let marked = TibetanSyllable {
+			long_vowel: true,
+			mark: SANSKRIT_MARKS.first(),
+			..syllable
+		};
+		assert!(validate(&marked).is_err());
+		assert!(!available_letters(&marked, Slot::Prefix).contains(&'ག'));
+	}
+
+	#[test]
+	fn available_letters_for_slots() {
+		let syllable = TibetanSyllable {
+			root: ROOTS.iter().find(|&t| t.tibetan == 'ཀ').unwrap(),
+			prefix: None,
+			superscript: ROOTS.iter().find(|&t| t.tibetan == 'ས'),
+			subscript: None,
+			wa_zur: false,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: None,
+			suffix: ROOTS.iter().find(|&t| t.tibetan == 'ན'),
+			second_suffix: None,
+			particle: None,
+			long_vowel: false,
+			mark: None,
+		};
+
+		assert_eq!(vec!['བ'], available_letters(&syllable, Slot::Prefix));
+		assert_eq!(
+			vec!['ར', 'ལ', 'ས'],
+			available_letters(&syllable, Slot::Superscript)
+		);
+		assert_eq!(vec!['ད'], available_letters(&syllable, Slot::SecondSuffix));
+	}
+
+	#[test]
+	fn wa_zur() {
+		let mut syllable = TibetanSyllable {
+			root: ROOTS.iter().find(|&t| t.tibetan == 'ག').unwrap(),
+			prefix: None,
+			superscript: None,
+			subscript: ROOTS.iter().find(|&t| t.tibetan == 'ར'),
+			wa_zur: true,
+			subjoined: vec![],
+			preceding_stacks: vec![],
+			vowel: None,
+			suffix: None,
+			second_suffix: None,
+			particle: None,
+			long_vowel: false,
+			mark: None,
+		};
+		assert_eq!(Ok(()), validate(&syllable));
+		assert_eq!(vec!['ཝ'], available_letters(&syllable, Slot::WaZur));
+
+		syllable.subscript = ROOTS.iter().find(|&t| t.tibetan == 'ལ');
+		assert_eq!(
+			Err(vec![SpellingError::WaZurNotAllowed("གླ".to_owned())]),
+			validate(&syllable)
+		);
+		assert!(available_letters(&syllable, Slot::WaZur).is_empty());
+	}
+}